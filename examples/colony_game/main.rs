@@ -186,14 +186,14 @@ pub fn place_house(
             board
                 .vertices
                 .get(vert)
-                .map_or(false, |val| val.vert_type != DevType::None)
+                .is_some_and(|val| val.vert_type != DevType::None)
         })
     });
 
     // Check if we have a road on one of our adjacent edges.
     let is_on_road = vert.adjacent_edges().is_some_and(|edges| {
         edges.iter().any(|edge| {
-            board.edges.get(edge).map_or(false, |val| {
+            board.edges.get(edge).is_some_and(|val| {
                 val.edge_type == EdgeType::Road && val.owning_player == player_id
             })
         })
@@ -233,16 +233,18 @@ pub fn place_road(
 
     // Is there a road adjacent to this road?
     let is_road_adjacent = edge.adjacent_edges().iter().any(|e| {
-        board.edges.get(e).map_or(false, |val| {
-            val.edge_type == EdgeType::Road && val.owning_player == player_id
-        })
+        board
+            .edges
+            .get(e)
+            .is_some_and(|val| val.edge_type == EdgeType::Road && val.owning_player == player_id)
     });
 
     // Do we have an owned and developed vertex adjacent to us?
     let is_development_adjacent = edge.endpoints().iter().any(|v| {
-        board.vertices.get(v).map_or(false, |val| {
-            val.vert_type != DevType::None && val.owning_player == player_id
-        })
+        board
+            .vertices
+            .get(v)
+            .is_some_and(|val| val.vert_type != DevType::None && val.owning_player == player_id)
     });
 
     // Either value must be false to be a valid placement
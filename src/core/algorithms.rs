@@ -132,10 +132,6 @@ mod tests {
 
     #[test]
     fn fmt() {
-        #[cfg(not(feature = "std"))]
-        use alloc::format;
-        #[cfg(feature = "std")]
-        use std::format;
         let err = FFError::InvalidSeed;
         assert_eq!(
             format!("{err}"),
@@ -0,0 +1,401 @@
+//! Neighbor bitmasks and autotiling rules.
+//!
+//! A neighbor mask records which neighbors of a tile match some predicate, one bit per direction.
+//! Masks are [`DirectionSet`]s, so bit `i` is the neighbor in the direction converted from `i`.
+//! Masks that only differ by a rotation or reflection of the tile can be drawn with the same
+//! tile variant, so masks can be canonicalised and looked up in an [`AutotileRules`] table.
+
+use crate::lib::*;
+
+use crate::core::direction::{Direction, DirectionSet};
+use crate::hex::coordinate::HexDirection;
+use crate::triangle::coordinate::TriDirection;
+
+/// Neighbor mask of a hex, see [`HexDirection`].
+pub type HexMask = DirectionSet<HexDirection>;
+
+/// Neighbor mask of a triangle or vertex, see [`TriDirection`].
+pub type TriMask = DirectionSet<TriDirection>;
+
+/// A rotation and reflection taking one mask to another.
+///
+/// The reflection, if any, is applied first and mirrors direction `i` onto direction `-i`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct MaskTransform {
+    /// Steps to rotate by, positive steps move bits forward.
+    pub rotation: i32,
+    /// Whether the mask is reflected before rotating.
+    pub reflected: bool,
+}
+
+/// The symmetries considered when canonicalising a mask.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum MaskSymmetry {
+    /// Every mask is canonical.
+    None,
+    /// Masks equal up to rotation share a canonical mask.
+    #[default]
+    Rotation,
+    /// Masks equal up to rotation and reflection share a canonical mask.
+    RotationReflection,
+}
+
+impl<D: Direction> DirectionSet<D> {
+    /// Build a mask from one flag per direction.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::autotile::TriMask;
+    ///
+    /// assert_eq!(TriMask::from_flags([true, false, true]).bits(), 0b101);
+    /// ```
+    pub fn from_flags(flags: impl IntoIterator<Item = bool>) -> Self {
+        let bits = flags
+            .into_iter()
+            .take(Self::COUNT as usize)
+            .enumerate()
+            .fold(0, |bits, (dir, flag)| bits | (flag as u8) << dir);
+        Self::from_bits(bits)
+    }
+
+    /// Reflect the mask, moving the bit of direction `i` to direction `-i`.
+    ///
+    /// Direction 0 and, for an even number of directions, the direction opposite it lie on the
+    /// mirror line.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::autotile::HexMask;
+    ///
+    /// assert_eq!(HexMask::from_bits(0b000011).reflect(), HexMask::from_bits(0b100001));
+    /// ```
+    pub fn reflect(&self) -> Self {
+        Self::from_flags((0..Self::COUNT as i32).map(|dir| self.contains(D::from(-dir))))
+    }
+
+    /// Apply a transform to the mask.
+    pub fn transform(&self, transform: MaskTransform) -> Self {
+        match transform.reflected {
+            true => self.reflect().rotate(transform.rotation),
+            false => self.rotate(transform.rotation),
+        }
+    }
+
+    /// Find the canonical mask of this mask under a symmetry.
+    ///
+    /// The canonical mask is the smallest mask reachable by the symmetry. The transform returned
+    /// takes this mask to the canonical mask.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::autotile::{HexMask, MaskSymmetry};
+    ///
+    /// let (canonical, transform) = HexMask::from_bits(0b011000).canonical(MaskSymmetry::Rotation);
+    /// assert_eq!(canonical, HexMask::from_bits(0b000011));
+    /// assert_eq!(HexMask::from_bits(0b011000).transform(transform), canonical);
+    /// ```
+    pub fn canonical(&self, symmetry: MaskSymmetry) -> (Self, MaskTransform) {
+        let reflections: &[bool] = match symmetry {
+            MaskSymmetry::None => return (*self, MaskTransform::default()),
+            MaskSymmetry::Rotation => &[false],
+            MaskSymmetry::RotationReflection => &[false, true],
+        };
+
+        reflections
+            .iter()
+            .flat_map(|reflected| {
+                (0..Self::COUNT as i32).map(|rotation| MaskTransform {
+                    rotation,
+                    reflected: *reflected,
+                })
+            })
+            .map(|transform| (self.transform(transform), transform))
+            .min_by_key(|(mask, _)| mask.bits())
+            .unwrap_or((*self, MaskTransform::default()))
+    }
+}
+
+impl MaskTransform {
+    /// The transform applying `other` first, then `self`.
+    pub fn then_after(&self, other: Self) -> Self {
+        Self {
+            rotation: match self.reflected {
+                true => self.rotation - other.rotation,
+                false => self.rotation + other.rotation,
+            },
+            reflected: self.reflected != other.reflected,
+        }
+    }
+
+    /// The transform undoing this one.
+    pub fn inverse(&self) -> Self {
+        match self.reflected {
+            true => *self,
+            false => Self {
+                rotation: -self.rotation,
+                reflected: false,
+            },
+        }
+    }
+
+    /// Normalise the rotation to `0..n` steps.
+    fn normalise(self, n: u32) -> Self {
+        Self {
+            rotation: self.rotation.rem_euclid(n as i32),
+            ..self
+        }
+    }
+}
+
+/// A table of tile variants indexed by neighbor mask.
+///
+/// Each variant is registered with the mask it was authored for. A lookup finds the variant
+/// registered for any mask equivalent under the table's symmetry, along with the transform to
+/// apply to the authored variant to fit the looked up mask.
+///
+/// # Example
+/// ```
+/// use gridava::core::autotile::{AutotileRules, HexMask, MaskSymmetry, MaskTransform};
+///
+/// let mut rules = AutotileRules::new(MaskSymmetry::Rotation);
+/// rules.insert(HexMask::from_bits(0b000001), "peninsula");
+///
+/// let (variant, transform) = rules.lookup(HexMask::from_bits(0b000100)).unwrap();
+/// assert_eq!(*variant, "peninsula");
+/// assert_eq!(transform, MaskTransform { rotation: 2, reflected: false });
+/// ```
+#[derive(Clone, Debug)]
+pub struct AutotileRules<V, D> {
+    symmetry: MaskSymmetry,
+    // Indexed by canonical mask, the transform takes the authored mask to the canonical mask.
+    // Only the first `1 << D::COUNT` entries are used, the array is sized for the largest mask.
+    variants: [Option<(V, MaskTransform)>; 1 << u8::BITS],
+    marker: PhantomData<D>,
+}
+
+impl<V, D: Direction> AutotileRules<V, D> {
+    /// Constructor for an empty rule table.
+    pub fn new(symmetry: MaskSymmetry) -> Self {
+        Self {
+            symmetry,
+            variants: core::array::from_fn(|_| None),
+            marker: PhantomData,
+        }
+    }
+
+    /// The symmetry masks are matched under.
+    pub fn symmetry(&self) -> MaskSymmetry {
+        self.symmetry
+    }
+
+    /// Register a variant authored for a mask.
+    ///
+    /// Replaces and returns any variant previously registered for an equivalent mask.
+    pub fn insert(&mut self, mask: DirectionSet<D>, variant: V) -> Option<V> {
+        let (canonical, transform) = mask.canonical(self.symmetry);
+        self.variants[canonical.bits() as usize]
+            .replace((variant, transform))
+            .map(|(variant, _)| variant)
+    }
+
+    /// Find the variant for a mask and the transform fitting it to the mask.
+    ///
+    /// Returns [`None`] if no equivalent mask was registered.
+    pub fn lookup(&self, mask: DirectionSet<D>) -> Option<(&V, MaskTransform)> {
+        let (canonical, transform) = mask.canonical(self.symmetry);
+        let (variant, authored) = self.variants[canonical.bits() as usize].as_ref()?;
+
+        // authored -> canonical -> mask
+        let transform = transform
+            .inverse()
+            .then_after(*authored)
+            .normalise(DirectionSet::<D>::COUNT);
+        Some((variant, transform))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bits() {
+        assert_eq!(HexMask::from_bits(0xFF).bits(), 0b111111);
+        assert_eq!(TriMask::from_bits(0xFF).bits(), 0b111);
+    }
+
+    #[test]
+    fn from_flags() {
+        assert_eq!(
+            HexMask::from_flags([true, false, false, true, false, true]).bits(),
+            0b101001
+        );
+        assert_eq!(TriMask::from_flags([true; 6]).bits(), 0b111);
+    }
+
+    #[test]
+    fn contains() {
+        let mask = HexMask::from_bits(0b100010);
+        assert!(mask.contains(HexDirection::from(1)));
+        assert!(mask.contains(HexDirection::from(5)));
+        assert!(mask.contains(HexDirection::from(-1)));
+        assert!(!mask.contains(HexDirection::from(0)));
+        assert_eq!(mask.len(), 2);
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(
+            TriMask::from_bits(0b001).rotate(1),
+            TriMask::from_bits(0b010)
+        );
+        assert_eq!(
+            TriMask::from_bits(0b100).rotate(1),
+            TriMask::from_bits(0b001)
+        );
+        assert_eq!(
+            TriMask::from_bits(0b011).rotate(-1),
+            TriMask::from_bits(0b101)
+        );
+        assert_eq!(
+            HexMask::from_bits(0b000111).rotate(9),
+            HexMask::from_bits(0b111000)
+        );
+
+        for bits in 0..64 {
+            let mask = HexMask::from_bits(bits);
+            assert_eq!(mask.rotate(6), mask);
+            assert_eq!(mask.rotate(2).rotate(-2), mask);
+            assert_eq!(mask.rotate(4).len(), mask.len());
+        }
+    }
+
+    #[test]
+    fn reflect() {
+        assert_eq!(
+            TriMask::from_bits(0b010).reflect(),
+            TriMask::from_bits(0b100)
+        );
+        assert_eq!(
+            HexMask::from_bits(0b001001).reflect(),
+            HexMask::from_bits(0b001001)
+        );
+        assert_eq!(
+            HexMask::from_bits(0b000110).reflect(),
+            HexMask::from_bits(0b110000)
+        );
+
+        for bits in 0..64 {
+            assert_eq!(
+                HexMask::from_bits(bits).reflect().reflect(),
+                HexMask::from_bits(bits)
+            );
+        }
+    }
+
+    #[test]
+    fn canonical() {
+        assert_eq!(
+            HexMask::from_bits(0b000010).canonical(MaskSymmetry::None),
+            (HexMask::from_bits(0b000010), MaskTransform::default())
+        );
+
+        // 14 classes under rotation, 13 under rotation and reflection.
+        for (symmetry, classes) in [
+            (MaskSymmetry::None, 64),
+            (MaskSymmetry::Rotation, 14),
+            (MaskSymmetry::RotationReflection, 13),
+        ] {
+            let mut canonicals = [false; 64];
+            for bits in 0..64 {
+                let mask = HexMask::from_bits(bits);
+                let (canonical, transform) = mask.canonical(symmetry);
+                assert_eq!(mask.transform(transform), canonical);
+                canonicals[canonical.bits() as usize] = true;
+            }
+            assert_eq!(canonicals.iter().filter(|c| **c).count(), classes);
+        }
+
+        // Triangles have 4 classes under rotation, reflection adds nothing.
+        let (canonical, _) = TriMask::from_bits(0b110).canonical(MaskSymmetry::RotationReflection);
+        assert_eq!(canonical, TriMask::from_bits(0b011));
+    }
+
+    #[test]
+    fn transform_composition() {
+        let transforms = (0..6).flat_map(|rotation| {
+            [false, true].map(|reflected| MaskTransform {
+                rotation,
+                reflected,
+            })
+        });
+        let mask = HexMask::from_bits(0b001011);
+
+        for a in transforms.clone() {
+            assert_eq!(mask.transform(a).transform(a.inverse()), mask);
+            for b in transforms.clone() {
+                assert_eq!(
+                    mask.transform(b).transform(a),
+                    mask.transform(a.then_after(b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rules() {
+        let mut rules = AutotileRules::new(MaskSymmetry::RotationReflection);
+        assert_eq!(rules.lookup(HexMask::from_bits(0)), None);
+
+        // A coast variant authored with land in the FrontRight and BackRight directions.
+        let authored = HexMask::from_bits(0b000110);
+        assert_eq!(rules.insert(authored, 'c'), None);
+        assert_eq!(rules.insert(HexMask::from_bits(0b111111), 'l'), None);
+        assert_eq!(rules.insert(HexMask::from_bits(0b111111), 'L'), Some('l'));
+
+        for bits in 0..64 {
+            let mask = HexMask::from_bits(bits);
+            match rules.lookup(mask) {
+                Some((variant, transform)) => {
+                    let authored = match variant {
+                        'c' => authored,
+                        _ => HexMask::from_bits(0b111111),
+                    };
+                    assert_eq!(authored.transform(transform), mask);
+                    assert!((0..6).contains(&transform.rotation));
+                }
+                None => assert!((0..6).all(|rotation| {
+                    authored.rotate(rotation) != mask && authored.reflect().rotate(rotation) != mask
+                })),
+            }
+        }
+
+        let (variant, transform) = rules.lookup(HexMask::from_bits(0b110000)).unwrap();
+        assert_eq!(*variant, 'c');
+        assert_eq!(
+            HexMask::from_bits(0b000110).transform(transform),
+            HexMask::from_bits(0b110000)
+        );
+    }
+
+    #[test]
+    fn rules_rotation_only() {
+        let mut rules = AutotileRules::new(MaskSymmetry::Rotation);
+        rules.insert(TriMask::from_bits(0b001), 0);
+
+        assert_eq!(
+            rules.lookup(TriMask::from_bits(0b100)),
+            Some((
+                &0,
+                MaskTransform {
+                    rotation: 2,
+                    reflected: false
+                }
+            ))
+        );
+        assert_eq!(rules.lookup(TriMask::from_bits(0b011)), None);
+        assert_eq!(rules.symmetry(), MaskSymmetry::Rotation);
+    }
+}
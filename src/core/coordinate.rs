@@ -0,0 +1,245 @@
+//! Traits shared by the coordinates of every grid.
+//!
+//! Each coordinate type has its own neighbor, distance, range and line queries with signatures
+//! suited to the grid. These traits expose the same queries uniformly so algorithms, such as
+//! searches or flood fills, can be written once for every grid. Implemented by
+//! [`Axial`](crate::hex::coordinate::Axial), [`Cube`](crate::hex::coordinate::Cube),
+//! [`Offset`](crate::hex::coordinate::Offset), [`Doubled`](crate::hex::coordinate::Doubled),
+//! [`Edge`](crate::hex::edge::Edge), [`Vertex`](crate::hex::vertex::Vertex) and
+//! [`Triangle`](crate::triangle::coordinate::Triangle).
+//!
+//! [`FracAxial`](crate::hex::coordinate::FracAxial) is not a grid coordinate, points between
+//! hexes have no neighbors. [`HexCell`](crate::hex::coordinate::HexCell) is not implemented
+//! either, cells of different resolutions do not form one grid, use the queries of its
+//! [`Axial`](crate::hex::coordinate::Axial) coordinate within a resolution.
+//!
+//! The inherent methods of a coordinate take precedence over these, so generic code should call
+//! them through a trait bound.
+
+use crate::lib::*;
+
+/// Coordinates with adjacent coordinates one step away.
+pub trait Neighbors: Sized {
+    /// Iterator over the neighbors of a coordinate.
+    type NeighborIter: Iterator<Item = Self>;
+
+    /// Get every coordinate one step away.
+    fn neighbors(&self) -> Self::NeighborIter;
+}
+
+/// Coordinates with a distance between them.
+pub trait Distance {
+    /// Compute the number of steps between two coordinates.
+    ///
+    /// Agrees with [`Neighbors`], neighbors are at a distance of 1.
+    fn distance(&self, b: Self) -> u32;
+}
+
+/// Coordinates that can enumerate the coordinates around them.
+pub trait Range: Sized {
+    /// Iterator over the coordinates within a range.
+    type RangeIter: Iterator<Item = Self>;
+
+    /// Lazily calculate every coordinate at a [`Distance`] of at most `range`.
+    ///
+    /// A negative range produces nothing.
+    fn range_iter(&self, range: i32) -> Self::RangeIter;
+}
+
+/// Coordinates that can draw a line between them.
+pub trait Line: Sized {
+    /// Iterator over the coordinates of a line.
+    type LineIter: Iterator<Item = Self>;
+
+    /// Lazily calculate the coordinates that form a line from self to b.
+    ///
+    /// Both ends are included, consecutive coordinates are neighbors.
+    fn line_iter(&self, b: Self) -> Self::LineIter;
+}
+
+/// A coordinate supporting every shared query.
+///
+/// Implemented for every type implementing the query traits.
+///
+/// # Example
+/// ```
+/// use gridava::core::coordinate::{Coordinate, Distance, Range};
+/// use gridava::hex::coordinate::{Axial, axial};
+/// use gridava::triangle::coordinate::Triangle;
+///
+/// // Count the coordinates at exactly a distance, for any grid.
+/// fn ring_len<C: Coordinate>(center: C, dist: u32) -> usize {
+///     center
+///         .range_iter(dist as i32)
+///         .filter(|coord| Distance::distance(coord, center) == dist)
+///         .count()
+/// }
+///
+/// assert_eq!(ring_len(axial!(0, 0), 2), 12);
+/// assert_eq!(ring_len(Triangle::new(0, 1, 0), 2), 6);
+/// ```
+pub trait Coordinate: Copy + Eq + Hash + Debug + Neighbors + Distance + Range + Line {}
+
+impl<C> Coordinate for C where C: Copy + Eq + Hash + Debug + Neighbors + Distance + Range + Line {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use super::*;
+    use crate::hex::{
+        coordinate::{
+            axial, doubled, offset, Axial, Cube, Doubled, DoubledLayout, Offset, OffsetLayout,
+        },
+        edge::{edge, Edge, EdgeDirection},
+        vertex::{Vertex, VertexDirection},
+    };
+    use crate::triangle::coordinate::Triangle;
+
+    // Breadth first search distances written once for every grid.
+    fn bfs<C: Coordinate>(start: C, max: u32) -> HashMap<C, u32> {
+        let mut dist = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(coord) = queue.pop_front() {
+            let next = dist[&coord] + 1;
+            if next > max {
+                continue;
+            }
+            for neighbor in coord.neighbors() {
+                dist.entry(neighbor).or_insert_with(|| {
+                    queue.push_back(neighbor);
+                    next
+                });
+            }
+        }
+        dist
+    }
+
+    fn check<C: Coordinate>(start: C, far: C) {
+        let max = 4;
+        let dist = bfs(start, max);
+
+        // Distances agree with the number of steps and the range holds the same coordinates.
+        for (coord, steps) in &dist {
+            assert_eq!(Distance::distance(coord, start), *steps, "{coord:?}");
+        }
+        let range: Vec<C> = start.range_iter(max as i32).collect();
+        assert_eq!(range.len(), dist.len());
+        assert!(range.iter().all(|coord| dist.contains_key(coord)));
+        assert_eq!(start.range_iter(-1).count(), 0);
+
+        // Lines step between neighbors.
+        let line: Vec<C> = start.line_iter(far).collect();
+        assert_eq!(line.first(), Some(&start));
+        assert_eq!(line.last(), Some(&far));
+        assert_eq!(line.len() as u32, Distance::distance(&start, far) + 1);
+        for pair in line.windows(2) {
+            assert!(pair[0].neighbors().any(|coord| coord == pair[1]));
+        }
+    }
+
+    #[test]
+    fn axial() {
+        check(axial!(1, -2), axial!(-4, 3));
+    }
+
+    #[test]
+    fn cube() {
+        check(Cube::from(axial!(1, -2)), Cube::from(axial!(5, 1)));
+    }
+
+    #[test]
+    fn offset() {
+        check(
+            offset!(1, -2, OffsetLayout::OddR),
+            offset!(-4, 3, OffsetLayout::OddR),
+        );
+        check(
+            offset!(0, 3, OffsetLayout::EvenQ),
+            offset!(3, -1, OffsetLayout::EvenQ),
+        );
+
+        // Coordinates are produced in the layout of self.
+        let start = offset!(0, 0, OffsetLayout::OddQ);
+        let far = axial!(2, 1).to_offset(OffsetLayout::EvenR);
+        assert_eq!(Distance::distance(&start, far), 3);
+        assert!(start
+            .line_iter(far)
+            .all(|coord| coord.layout == OffsetLayout::OddQ));
+        assert!(start
+            .range_iter(2)
+            .all(|coord| coord.layout == OffsetLayout::OddQ));
+    }
+
+    #[test]
+    fn doubled() {
+        check(
+            doubled!(1, -1, DoubledLayout::Width),
+            doubled!(-4, 2, DoubledLayout::Width),
+        );
+        check(
+            doubled!(0, 4, DoubledLayout::Height),
+            doubled!(3, -1, DoubledLayout::Height),
+        );
+
+        // Coordinates are produced in the layout of self.
+        let start = doubled!(0, 0, DoubledLayout::Height);
+        let far = axial!(2, 1).to_doubled(DoubledLayout::Width);
+        assert_eq!(Distance::distance(&start, far), 3);
+        assert_eq!(
+            start.line_iter(far).last(),
+            Some(axial!(2, 1).to_doubled(DoubledLayout::Height))
+        );
+
+        // Invalid coordinates are not hexes.
+        let invalid = doubled!(1, 0, DoubledLayout::Width);
+        assert_eq!(Neighbors::neighbors(&invalid).count(), 0);
+        assert_eq!(invalid.range_iter(2).count(), 0);
+        assert_eq!(invalid.line_iter(start).count(), 0);
+        assert_eq!(start.line_iter(invalid).count(), 0);
+        assert_eq!(Distance::distance(&start, invalid), u32::MAX);
+    }
+
+    #[test]
+    fn triangle() {
+        check(Triangle::new(0, 1, 0), Triangle::new(-2, 4, 0));
+        check(Triangle::new(1, 1, 0), Triangle::new(3, -1, -1));
+    }
+
+    #[test]
+    fn edge() {
+        check(
+            edge!(1, -2, EdgeDirection::West),
+            edge!(-2, 1, EdgeDirection::NorthEast),
+        );
+        check(
+            edge!(0, 3, EdgeDirection::NorthWest),
+            edge!(2, 2, EdgeDirection::NorthWest),
+        );
+
+        // A line to the same edge is only that edge.
+        let edge = edge!(-1, 0, EdgeDirection::NorthEast);
+        assert!(edge.line_iter(edge).eq([edge]));
+    }
+
+    #[test]
+    fn vertex() {
+        check(
+            axial!(0, 0).vertex(VertexDirection::Up),
+            axial!(3, -1).vertex(VertexDirection::DownLeft),
+        );
+        check(
+            axial!(2, 1).vertex(VertexDirection::Down),
+            axial!(-1, 1).vertex(VertexDirection::UpRight),
+        );
+
+        // Hex centers are not vertices.
+        let center = Vertex::new(0, 0, 0);
+        assert_eq!(center.neighbors().count(), 0);
+        assert_eq!(center.range_iter(2).count(), 0);
+
+        let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+        assert_eq!(center.line_iter(vertex).count(), 0);
+        assert_eq!(vertex.line_iter(center).count(), 0);
+    }
+}
@@ -0,0 +1,312 @@
+//! Compact sets of grid directions.
+//!
+//! A [`DirectionSet`] stores one bit per direction of a grid, e.g. which sides of a tile are
+//! walls or which way a river flows. Bit `i` is the direction converted from `i`. Sets also serve
+//! as neighbor masks for autotiling, see [`autotile`](crate::core::autotile).
+
+use crate::lib::*;
+
+/// The directions of travel on a grid.
+///
+/// Directions are numbered `0..COUNT` by their `i32` conversions, converting from an `i32` wraps
+/// around.
+pub trait Direction: Copy + From<i32> + Into<i32> {
+    /// Number of directions, in the range `1..=8` so a [`DirectionSet`] fits them in a byte.
+    ///
+    /// Sets of a direction with any other count fail to compile.
+    ///
+    /// ```compile_fail
+    /// use gridava::core::direction::{Direction, DirectionSet};
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct Compass(i32);
+    ///
+    /// impl From<i32> for Compass {
+    ///     fn from(value: i32) -> Self {
+    ///         Compass(value.rem_euclid(16))
+    ///     }
+    /// }
+    ///
+    /// impl From<Compass> for i32 {
+    ///     fn from(value: Compass) -> Self {
+    ///         value.0
+    ///     }
+    /// }
+    ///
+    /// impl Direction for Compass {
+    ///     const COUNT: u32 = 16;
+    /// }
+    ///
+    /// DirectionSet::<Compass>::all();
+    /// ```
+    const COUNT: u32;
+}
+
+/// A set of directions stored as bitflags.
+///
+/// # Example
+/// ```
+/// use gridava::hex::coordinate::{HexDirection, HexDirectionSet};
+///
+/// let mut walls = HexDirectionSet::from(HexDirection::Front) | HexDirection::Back.into();
+/// walls.insert(HexDirection::BackLeft);
+/// assert!(walls.contains(HexDirection::Back));
+/// assert_eq!(walls.len(), 3);
+/// assert_eq!(
+///     walls.iter().collect::<Vec<_>>(),
+///     [HexDirection::Front, HexDirection::Back, HexDirection::BackLeft]
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "u8", into = "u8", bound = "D: Direction")
+)]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct DirectionSet<D> {
+    bits: u8,
+    marker: PhantomData<D>,
+}
+
+impl<D: Direction> DirectionSet<D> {
+    // Checked copy of `D::COUNT`, use it instead of `D::COUNT`.
+    pub(crate) const COUNT: u32 = {
+        assert!(
+            D::COUNT >= 1 && D::COUNT <= u8::BITS,
+            "a direction set holds 1 to 8 directions"
+        );
+        D::COUNT
+    };
+
+    const FULL: u8 = ((1u16 << Self::COUNT) - 1) as u8;
+
+    /// Constructor for an empty set.
+    pub const fn new() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Constructor for a set of every direction.
+    pub const fn all() -> Self {
+        Self::from_bits(Self::FULL)
+    }
+
+    /// Constructor for a set from its raw bits, bits above the last direction are discarded.
+    pub const fn from_bits(bits: u8) -> Self {
+        Self {
+            bits: bits & Self::FULL,
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the raw bits of the set.
+    pub const fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Determines if a direction is in the set.
+    pub fn contains(&self, dir: D) -> bool {
+        self.bits & Self::bit(dir) != 0
+    }
+
+    /// Add a direction to the set.
+    ///
+    /// Returns whether the direction was newly added.
+    pub fn insert(&mut self, dir: D) -> bool {
+        let added = !self.contains(dir);
+        self.bits |= Self::bit(dir);
+        added
+    }
+
+    /// Remove a direction from the set.
+    ///
+    /// Returns whether the direction was in the set.
+    pub fn remove(&mut self, dir: D) -> bool {
+        let removed = self.contains(dir);
+        self.bits &= !Self::bit(dir);
+        removed
+    }
+
+    /// Number of directions in the set.
+    pub fn len(&self) -> u32 {
+        self.bits.count_ones()
+    }
+
+    /// Determines if the set has no directions.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Rotate the set, moving direction `i` to direction `i + rot_dir`.
+    ///
+    /// For [`HexDirection`](crate::hex::coordinate::HexDirection)s positive `rot_dir` denotes CW
+    /// and negative CCW, matching [`Axial::rotate`](crate::hex::coordinate::Axial::rotate).
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{HexDirection, HexDirectionSet};
+    ///
+    /// let set = HexDirectionSet::from(HexDirection::FrontLeft).rotate(2);
+    /// assert_eq!(set, HexDirectionSet::from(HexDirection::FrontRight));
+    /// ```
+    pub fn rotate(&self, rot_dir: i32) -> Self {
+        let steps = rot_dir.rem_euclid(Self::COUNT as i32) as u32;
+        let bits = self.bits as u16;
+        Self::from_bits((bits << steps | bits >> (Self::COUNT - steps)) as u8)
+    }
+
+    /// Iterate the directions in the set, in order of their `i32` conversions.
+    pub fn iter(&self) -> impl Iterator<Item = D> {
+        let bits = self.bits;
+        (0..Self::COUNT as i32)
+            .filter(move |dir| bits >> dir & 1 == 1)
+            .map(D::from)
+    }
+
+    fn bit(dir: D) -> u8 {
+        1 << dir.into().rem_euclid(Self::COUNT as i32)
+    }
+}
+
+impl<D: Direction> Default for DirectionSet<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bits above the last direction are discarded, see [`DirectionSet::from_bits`].
+impl<D: Direction> From<u8> for DirectionSet<D> {
+    fn from(value: u8) -> Self {
+        Self::from_bits(value)
+    }
+}
+
+impl<D> From<DirectionSet<D>> for u8 {
+    fn from(value: DirectionSet<D>) -> Self {
+        value.bits
+    }
+}
+
+impl<D: Direction> From<D> for DirectionSet<D> {
+    fn from(value: D) -> Self {
+        Self::from_bits(Self::bit(value))
+    }
+}
+
+impl<D: Direction> FromIterator<D> for DirectionSet<D> {
+    fn from_iter<I: IntoIterator<Item = D>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for dir in iter {
+            set.insert(dir);
+        }
+        set
+    }
+}
+
+impl<D: Direction> BitOr for DirectionSet<D> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::from_bits(self.bits | rhs.bits)
+    }
+}
+
+impl<D: Direction> BitAnd for DirectionSet<D> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::from_bits(self.bits & rhs.bits)
+    }
+}
+
+impl<D: Direction> Not for DirectionSet<D> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::from_bits(!self.bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hex::coordinate::{HexDirection, HexDirectionSet};
+    use crate::triangle::coordinate::{TriDirection, TriDirectionSet};
+
+    #[test]
+    fn insert_remove() {
+        let mut set = HexDirectionSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(HexDirection::Back));
+        assert!(!set.insert(HexDirection::Back));
+        assert!(set.insert(HexDirection::FrontRight));
+        assert_eq!(set.bits(), 0b001010);
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(HexDirection::Back));
+        assert!(!set.remove(HexDirection::Back));
+        assert_eq!(set, HexDirectionSet::from(HexDirection::FrontRight));
+    }
+
+    #[test]
+    fn operators() {
+        let front: HexDirectionSet = [
+            HexDirection::FrontLeft,
+            HexDirection::Front,
+            HexDirection::FrontRight,
+        ]
+        .into_iter()
+        .collect();
+        let right: HexDirectionSet = [HexDirection::FrontRight, HexDirection::BackRight]
+            .into_iter()
+            .collect();
+
+        assert_eq!((front | right).len(), 4);
+        assert_eq!(
+            front & right,
+            HexDirectionSet::from(HexDirection::FrontRight)
+        );
+        assert_eq!((!front).bits(), 0b011100);
+        assert_eq!(!HexDirectionSet::new(), HexDirectionSet::all());
+        assert_eq!(HexDirectionSet::from_bits(0xff), HexDirectionSet::all());
+        assert_eq!(TriDirectionSet::all().bits(), 0b111);
+    }
+
+    #[test]
+    fn rotate() {
+        let set = HexDirectionSet::from_bits(0b100011);
+        assert_eq!(set.rotate(1).bits(), 0b000111);
+        assert_eq!(set.rotate(-1).bits(), 0b110001);
+        assert_eq!(set.rotate(6), set);
+
+        let set = TriDirectionSet::from(TriDirection::Base);
+        assert_eq!(set.rotate(1), TriDirectionSet::from(TriDirection::Left));
+    }
+
+    #[test]
+    fn iter() {
+        assert!(TriDirectionSet::all().iter().eq([
+            TriDirection::Left,
+            TriDirection::Right,
+            TriDirection::Base
+        ]));
+        assert!(HexDirectionSet::all().iter().eq(HexDirection::iter()));
+        assert_eq!(HexDirectionSet::new().iter().count(), 0);
+        assert_eq!(
+            HexDirectionSet::all().iter().collect::<HexDirectionSet>(),
+            HexDirectionSet::all()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let set = HexDirectionSet::from(HexDirection::Back) | HexDirection::FrontLeft.into();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "40");
+        assert_eq!(serde_json::from_str::<HexDirectionSet>(&json).unwrap(), set);
+
+        // Bits above the last direction are discarded.
+        let set: TriDirectionSet = serde_json::from_str("255").unwrap();
+        assert_eq!(set, TriDirectionSet::all());
+        assert_eq!(set.len(), 3);
+    }
+}
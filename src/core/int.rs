@@ -0,0 +1,202 @@
+//! Integer types usable as coordinate components.
+
+use crate::lib::*;
+
+/// A signed primitive integer usable as the component of a coordinate.
+///
+/// Coordinates such as [`Axial`](crate::hex::coordinate::Axial) and
+/// [`Triangle`](crate::triangle::coordinate::Triangle) default to `i32` components. Narrower
+/// components save space, e.g. when sending coordinates over the network, while wider components
+/// allow for larger maps before arithmetic overflows.
+///
+/// Converting a coordinate between component types is done with `cast`, for lossless conversions,
+/// or `try_cast`, which fails if a component does not fit the narrower type.
+///
+/// Queries that work in floating point, such as lines and directions, convert through `f64` and
+/// so are only exact for components within `±2^53`.
+pub trait SignedInt:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + From<i8>
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Computes the absolute value.
+    fn abs(self) -> Self;
+
+    /// Widens to an `i128`, which holds every value of every implementing type.
+    fn to_i128(self) -> i128;
+    /// Narrows from an `i128`, [`None`] if the value does not fit.
+    fn try_from_i128(value: i128) -> Option<Self>;
+    /// Converts to the nearest `f64`.
+    fn to_f64(self) -> f64;
+    /// Converts from an `f64` as `as` does, truncating toward zero and saturating at the bounds
+    /// of the type.
+    fn from_f64(value: f64) -> Self;
+
+    /// Addition, [`None`] on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Subtraction, [`None`] on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Multiplication, [`None`] on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Division, [`None`] on overflow or division by zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Negation, [`None`] on overflow.
+    fn checked_neg(self) -> Option<Self>;
+    /// Absolute value, [`None`] on overflow.
+    fn checked_abs(self) -> Option<Self>;
+
+    /// Addition, clamped to the bounds of the type.
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Subtraction, clamped to the bounds of the type.
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Multiplication, clamped to the bounds of the type.
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_signed_int {
+    ($($int:ty),*) => {
+        $(
+            impl SignedInt for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$int>::MIN;
+                const MAX: Self = <$int>::MAX;
+
+                #[inline]
+                fn abs(self) -> Self {
+                    <$int>::abs(self)
+                }
+
+                #[inline]
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                #[inline]
+                fn try_from_i128(value: i128) -> Option<Self> {
+                    <$int>::try_from(value).ok()
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as $int
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, rhs)
+                }
+
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, rhs)
+                }
+
+                #[inline]
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_div(self, rhs)
+                }
+
+                #[inline]
+                fn checked_neg(self) -> Option<Self> {
+                    <$int>::checked_neg(self)
+                }
+
+                #[inline]
+                fn checked_abs(self) -> Option<Self> {
+                    <$int>::checked_abs(self)
+                }
+
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$int>::saturating_add(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$int>::saturating_sub(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$int>::saturating_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum<T: SignedInt>(values: &[T]) -> T {
+        values.iter().fold(T::ZERO, |acc, value| acc + value.abs())
+    }
+
+    #[test]
+    fn signed_int() {
+        assert_eq!(sum(&[1i8, -2, 3]), 6);
+        assert_eq!(sum(&[i64::MAX / 2, -(i64::MAX / 2)]), i64::MAX - 1);
+        assert_eq!(<i16 as SignedInt>::MIN, i16::MIN);
+        assert_eq!(<isize as SignedInt>::ONE, 1);
+    }
+
+    #[test]
+    fn checked_and_saturating() {
+        assert_eq!(SignedInt::checked_add(i8::MAX, 1), None);
+        assert_eq!(SignedInt::checked_sub(-2i32, 3), Some(-5));
+        assert_eq!(SignedInt::checked_neg(i16::MIN), None);
+        assert_eq!(SignedInt::checked_abs(i64::MIN), None);
+        assert_eq!(SignedInt::checked_div(1i32, 0), None);
+        assert_eq!(SignedInt::saturating_mul(i8::MAX, 2), i8::MAX);
+        assert_eq!(SignedInt::saturating_sub(i32::MIN, 1), i32::MIN);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(SignedInt::to_i128(isize::MIN), isize::MIN as i128);
+        assert_eq!(
+            <i16 as SignedInt>::try_from_i128(i16::MAX as i128 + 1),
+            None
+        );
+        assert_eq!(<i64 as SignedInt>::try_from_i128(-5), Some(-5));
+        assert_eq!(SignedInt::to_f64(-3i32), -3.0);
+        assert_eq!(<i8 as SignedInt>::from_f64(-2.7), -2);
+        assert_eq!(<i8 as SignedInt>::from_f64(1000.0), i8::MAX);
+    }
+}
@@ -13,6 +13,17 @@ pub enum Axes3D {
     Z,
 }
 
+/// Winding order used when traversing coordinates around a center.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum Winding {
+    /// Traverse clockwise, matching positive `rot_dir` rotations.
+    #[default]
+    Clockwise,
+    /// Traverse counterclockwise, matching negative `rot_dir` rotations.
+    CounterClockwise,
+}
+
 /// Linear interpolation from a to b along t.
 pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
     a + (b - a) * t
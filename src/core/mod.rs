@@ -1,8 +1,14 @@
 //! Core implementations for grids of all types.
 
 pub mod algorithms;
+pub mod autotile;
 pub mod collection;
+pub mod coordinate;
+pub mod direction;
 pub mod grid;
+pub mod int;
 pub mod misc;
+pub mod parse;
 pub mod tile;
+pub mod topology;
 pub mod transform;
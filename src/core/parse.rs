@@ -0,0 +1,96 @@
+//! Parsing of coordinates from their textual forms.
+//!
+//! Coordinates are displayed as their type name followed by their components in parenthesis,
+//! e.g. `Axial(1, -2)`, and parsed back from the same form. Whitespace around components is
+//! ignored.
+
+use crate::lib::*;
+
+/// Error for parsing coordinates and labels.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ParseCoordError {
+    /// The text does not have the expected structure.
+    Format,
+    /// A component is not a valid number, or does not fit the component type.
+    Component,
+    /// The text is well formed but does not describe a valid coordinate.
+    Invalid,
+}
+
+impl Display for ParseCoordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCoordError::Format => write!(f, "text is not in the form of a coordinate"),
+            ParseCoordError::Component => write!(f, "coordinate component is invalid"),
+            ParseCoordError::Invalid => write!(f, "text does not describe a valid coordinate"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCoordError {}
+
+/// Split `name(a, b, ...)` into exactly `N` trimmed components.
+pub(crate) fn components<'a, const N: usize>(
+    text: &'a str,
+    name: &str,
+) -> Result<[&'a str; N], ParseCoordError> {
+    let inner = text
+        .trim()
+        .strip_prefix(name)
+        .and_then(|rest| rest.trim_start().strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or(ParseCoordError::Format)?;
+
+    let mut parts = inner.split(',').map(str::trim);
+    let mut out = [""; N];
+    for part in out.iter_mut() {
+        *part = parts.next().ok_or(ParseCoordError::Format)?;
+    }
+    match parts.next() {
+        Some(_) => Err(ParseCoordError::Format),
+        None => Ok(out),
+    }
+}
+
+/// Parse a single component.
+pub(crate) fn component<T: FromStr>(text: &str) -> Result<T, ParseCoordError> {
+    text.parse().map_err(|_| ParseCoordError::Component)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_components() {
+        assert_eq!(components::<2>("Axial(1, -2)", "Axial"), Ok(["1", "-2"]));
+        assert_eq!(
+            components::<3>("  Edge ( 1 ,2,West ) ", "Edge"),
+            Ok(["1", "2", "West"])
+        );
+        assert_eq!(
+            components::<2>("Axial(1, 2, 3)", "Axial"),
+            Err(ParseCoordError::Format)
+        );
+        assert_eq!(
+            components::<2>("Axial(1)", "Axial"),
+            Err(ParseCoordError::Format)
+        );
+        assert_eq!(
+            components::<2>("Cube(1, 2)", "Axial"),
+            Err(ParseCoordError::Format)
+        );
+        assert_eq!(
+            components::<2>("Axial(1, 2", "Axial"),
+            Err(ParseCoordError::Format)
+        );
+    }
+
+    #[test]
+    fn parse_component() {
+        assert_eq!(component::<i32>("-12"), Ok(-12));
+        assert_eq!(component::<i8>("300"), Err(ParseCoordError::Component));
+        assert_eq!(component::<i32>("x"), Err(ParseCoordError::Component));
+    }
+}
@@ -0,0 +1,240 @@
+//! Map topologies describing how a grid connects across its edges.
+
+use crate::lib::*;
+
+/// Error for invalid [`Topology`] dimensions.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum TopologyError {
+    /// The width is 0 or above [`i32::MAX`].
+    InvalidWidth(u32),
+    /// The height is odd, 0 or above [`i32::MAX`].
+    InvalidHeight(u32),
+}
+
+impl Display for TopologyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TopologyError::InvalidWidth(width) => {
+                write!(f, "width {width} must be in the range 1..=i32::MAX")
+            }
+            TopologyError::InvalidHeight(height) => {
+                write!(
+                    f,
+                    "height {height} must be even and in the range 2..=i32::MAX"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TopologyError {}
+
+/// How the edges of a map connect.
+///
+/// Maps are measured in rows and columns of tiles. For hex grids a column is an
+/// [`OddR`](crate::hex::coordinate::OffsetLayout::OddR) offset column, for triangle grids it is a
+/// single edge length along a row, two triangle faces.
+///
+/// Built with [`Topology::plane`], [`Topology::cylinder`] or [`Topology::torus`], which reject
+/// dimensions that cannot wrap.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TopologyDims"))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct Topology {
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+impl Topology {
+    /// Constructor for an infinite plane, nothing wraps.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    ///
+    /// assert_eq!(Topology::plane(), Topology::default());
+    /// ```
+    pub const fn plane() -> Self {
+        Self {
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Constructor for a map that wraps east-west, leaving north-south unbounded.
+    ///
+    /// `width` is the number of columns before wrapping, it must be in the range
+    /// `1..=i32::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::{Topology, TopologyError};
+    ///
+    /// assert!(Topology::cylinder(8).is_ok());
+    /// assert_eq!(Topology::cylinder(0), Err(TopologyError::InvalidWidth(0)));
+    /// ```
+    pub const fn cylinder(width: u32) -> Result<Self, TopologyError> {
+        if width == 0 || width > i32::MAX as u32 {
+            return Err(TopologyError::InvalidWidth(width));
+        }
+
+        Ok(Self {
+            width: Some(width),
+            height: None,
+        })
+    }
+
+    /// Constructor for a map that wraps both east-west and north-south.
+    ///
+    /// `width` is the number of columns before wrapping, as in [`Topology::cylinder`]. `height`
+    /// is the number of rows before wrapping. Rows on both hex and triangle grids alternate their
+    /// offset, so it must be even and in the range `2..=i32::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::{Topology, TopologyError};
+    ///
+    /// assert!(Topology::torus(8, 4).is_ok());
+    /// assert_eq!(Topology::torus(8, 5), Err(TopologyError::InvalidHeight(5)));
+    /// ```
+    pub const fn torus(width: u32, height: u32) -> Result<Self, TopologyError> {
+        if width == 0 || width > i32::MAX as u32 {
+            return Err(TopologyError::InvalidWidth(width));
+        }
+        if height == 0 || height & 1 != 0 || height > i32::MAX as u32 {
+            return Err(TopologyError::InvalidHeight(height));
+        }
+
+        Ok(Self {
+            width: Some(width),
+            height: Some(height),
+        })
+    }
+
+    /// Get the number of columns before wrapping, if the map wraps east-west.
+    pub const fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    /// Get the number of rows before wrapping, if the map wraps north-south.
+    pub const fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Get the (columns, rows) after which the topology wraps, if at all.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    ///
+    /// assert_eq!(Topology::plane().periods(), (None, None));
+    /// assert_eq!(Topology::cylinder(8).unwrap().periods(), (Some(8), None));
+    /// assert_eq!(Topology::torus(8, 6).unwrap().periods(), (Some(8), Some(6)));
+    /// ```
+    pub fn periods(&self) -> (Option<i32>, Option<i32>) {
+        // Both fit in an i32, see the constructors.
+        (
+            self.width.map(|width| width as i32),
+            self.height.map(|height| height as i32),
+        )
+    }
+
+    // Offsets of the nearby copies of a tile that wrapping produces, in (columns, rows).
+    pub(crate) fn images(&self) -> impl Iterator<Item = (i32, i32)> {
+        let (cols, rows) = match self.periods() {
+            (None, _) => (0, 0),
+            (Some(_), None) => (1, 0),
+            (Some(_), Some(_)) => (1, 1),
+        };
+
+        (-cols..=cols).flat_map(move |col| (-rows..=rows).map(move |row| (col, row)))
+    }
+}
+
+// Unchecked form of a topology, deserialized then validated by the constructors.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct TopologyDims {
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TopologyDims> for Topology {
+    type Error = TopologyError;
+
+    fn try_from(value: TopologyDims) -> Result<Self, Self::Error> {
+        match (value.width, value.height) {
+            (None, None) => Ok(Topology::plane()),
+            (Some(width), None) => Topology::cylinder(width),
+            (Some(width), Some(height)) => Topology::torus(width, height),
+            (None, Some(height)) => Err(TopologyError::InvalidHeight(height)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(Topology::default(), Topology::plane());
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Topology::cylinder(0), Err(TopologyError::InvalidWidth(0)));
+        assert_eq!(
+            Topology::cylinder(u32::MAX),
+            Err(TopologyError::InvalidWidth(u32::MAX))
+        );
+        assert_eq!(
+            Topology::cylinder(i32::MAX as u32).unwrap().width(),
+            Some(i32::MAX as u32)
+        );
+
+        assert_eq!(Topology::torus(0, 2), Err(TopologyError::InvalidWidth(0)));
+        assert_eq!(Topology::torus(3, 0), Err(TopologyError::InvalidHeight(0)));
+        assert_eq!(Topology::torus(3, 1), Err(TopologyError::InvalidHeight(1)));
+        assert_eq!(Topology::torus(3, 5), Err(TopologyError::InvalidHeight(5)));
+        assert_eq!(
+            Topology::torus(3, i32::MAX as u32 + 1),
+            Err(TopologyError::InvalidHeight(i32::MAX as u32 + 1))
+        );
+
+        let torus = Topology::torus(3, 6).unwrap();
+        assert_eq!((torus.width(), torus.height()), (Some(3), Some(6)));
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(Topology::plane().periods(), (None, None));
+        assert_eq!(Topology::cylinder(1).unwrap().periods(), (Some(1), None));
+        assert_eq!(Topology::torus(3, 2).unwrap().periods(), (Some(3), Some(2)));
+        assert_eq!(
+            Topology::cylinder(i32::MAX as u32).unwrap().periods(),
+            (Some(i32::MAX), None)
+        );
+    }
+
+    #[test]
+    fn images() {
+        assert_eq!(Topology::plane().images().count(), 1);
+        assert_eq!(Topology::cylinder(4).unwrap().images().count(), 3);
+        assert_eq!(Topology::torus(4, 4).unwrap().images().count(), 9);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let torus = Topology::torus(4, 6).unwrap();
+        let json = serde_json::to_string(&torus).unwrap();
+        assert_eq!(serde_json::from_str::<Topology>(&json).unwrap(), torus);
+
+        // Dimensions the constructors reject are rejected when deserializing.
+        assert!(serde_json::from_str::<Topology>(r#"{"width":4,"height":5}"#).is_err());
+        assert!(serde_json::from_str::<Topology>(r#"{"width":0,"height":null}"#).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+//! Outlines of regions in a hex grid.
+//!
+//! The boundary of a set of hexes is made of the edges between a hex in the set and a hex outside
+//! of it. [`trace_boundary`] orders these edges into closed loops, one for the outside of each
+//! connected region and one for each hole inside a region, e.g. to draw territory borders.
+
+use crate::lib::*;
+
+use crate::core::misc::Winding;
+
+use super::{
+    coordinate::{axial, Axial, HexDirection},
+    edge::Edge,
+    vertex::{Vertex, VertexDirection},
+};
+
+#[cfg(feature = "std")]
+use super::grid::WSConverter;
+
+/// A closed loop of the boundary of a region.
+///
+/// The loop is walked with the region on its right hand side, so loops around the outside of a
+/// region wind clockwise and loops around a hole wind counterclockwise. Clockwise matches positive
+/// `rot_dir` rotations and the screen when the world space y axis points down. `vertices[i]` is
+/// the corner where `edges[i]` starts, the last edge ends back at the first corner.
+///
+/// See [`trace_boundary`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BoundaryLoop {
+    /// Edges of the loop in order of traversal.
+    pub edges: Vec<Edge>,
+    /// Corners of the loop in order of traversal.
+    pub vertices: Vec<Vertex>,
+}
+
+impl BoundaryLoop {
+    /// Compute the winding of the loop.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::misc::Winding;
+    /// use gridava::hex::boundary::trace_boundary;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let loops = trace_boundary([axial!(0, 0)]);
+    /// assert_eq!(loops[0].winding(), Winding::Clockwise);
+    /// ```
+    pub fn winding(&self) -> Winding {
+        // Shoelace formula on the corners scaled to integers, thirds of a tile. Corners are taken
+        // relative to the first one in i64 and the area is summed in i128, so loops anywhere on
+        // the grid cannot overflow.
+        let Some(first) = self.vertices.first() else {
+            return Winding::Clockwise;
+        };
+        let origin = first.into_inner();
+        let point = |vertex: &Vertex| {
+            let coord = vertex.into_inner();
+            let (x, y, z) = (
+                coord.x as i64 - origin.x as i64,
+                coord.y as i64 - origin.y as i64,
+                coord.z as i64 - origin.z as i64,
+            );
+            ((2 * x - y - z) as i128, (2 * y - x - z) as i128)
+        };
+        let area: i128 = self
+            .vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| {
+                let (a, b) = (point(a), point(b));
+                a.0 * b.1 - b.0 * a.1
+            })
+            .sum();
+
+        match area {
+            0.. => Winding::Clockwise,
+            _ => Winding::CounterClockwise,
+        }
+    }
+
+    /// Determines if the loop goes around a hole inside a region.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::boundary::trace_boundary;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// // A ring of hexes has an outside and a hole.
+    /// let loops = trace_boundary(axial!(0, 0).neighbors());
+    /// assert_eq!(loops.iter().filter(|boundary| boundary.is_hole()).count(), 1);
+    /// ```
+    pub fn is_hole(&self) -> bool {
+        self.winding() == Winding::CounterClockwise
+    }
+
+    /// Convert the loop to a closed polyline in world space.
+    ///
+    /// The first corner is repeated at the end to close the polyline.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::boundary::trace_boundary;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::grid::{WSConverter, HexOrientation};
+    ///
+    /// let converter = WSConverter { size: 32.0, orientation: HexOrientation::PointyTop };
+    /// let polyline = trace_boundary([axial!(0, 0)])[0].to_world(&converter);
+    /// assert_eq!(polyline.len(), 7);
+    /// assert_eq!(polyline.first(), polyline.last());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_world(&self, converter: &WSConverter) -> Vec<(f64, f64)> {
+        self.vertices
+            .iter()
+            .chain(self.vertices.first())
+            .map(|vertex| converter.vertex_to_world(*vertex))
+            .collect()
+    }
+}
+
+/// Trace the boundary of a set of hexes into closed loops.
+///
+/// Each connected region produces a [`Winding::Clockwise`] loop around its outside and a
+/// [`Winding::CounterClockwise`] loop around each of its holes, see [`BoundaryLoop`]. Hexes
+/// sharing a corner always share an edge, so loops never touch. Duplicate coordinates are ignored.
+///
+/// # Example
+/// ```
+/// use gridava::core::misc::Winding;
+/// use gridava::hex::boundary::trace_boundary;
+/// use gridava::hex::coordinate::{Axial, axial};
+///
+/// let loops = trace_boundary([axial!(0, 0), axial!(1, 0)]);
+/// assert_eq!(loops.len(), 1);
+/// assert_eq!(loops[0].edges.len(), 10);
+/// assert_eq!(loops[0].winding(), Winding::Clockwise);
+/// ```
+pub fn trace_boundary<I>(coords: I) -> Vec<BoundaryLoop>
+where
+    I: IntoIterator<Item = Axial>,
+{
+    let region: BTreeSet<(i32, i32)> = coords.into_iter().map(|coord| (coord.q, coord.r)).collect();
+    let key = |vertex: Vertex| {
+        let coord = vertex.into_inner();
+        (coord.x, coord.y, coord.z)
+    };
+
+    // Boundary edges walked with the region on the right, keyed by the corner they start at.
+    // Every corner touches 3 hexes so it starts at most one boundary edge.
+    let mut next = BTreeMap::new();
+    for &(q, r) in &region {
+        let coord = axial!(q, r);
+        for dir in HexDirection::iter() {
+            let neighbor = coord.neighbor(dir);
+            if region.contains(&(neighbor.q, neighbor.r)) {
+                continue;
+            }
+
+            // The corners of the side facing a direction follow it clockwise.
+            let start = coord.vertex(VertexDirection::from(i32::from(dir) + 1));
+            let end = coord.vertex(VertexDirection::from(i32::from(dir) + 2));
+            next.insert(key(start), (coord.edge(dir), start, end));
+        }
+    }
+
+    let mut loops = Vec::new();
+    while let Some((_, (edge, start, mut end))) = next.pop_first() {
+        let mut boundary = BoundaryLoop {
+            edges: Vec::new(),
+            vertices: Vec::new(),
+        };
+        boundary.edges.push(edge);
+        boundary.vertices.push(start);
+
+        while let Some((edge, start, to)) = next.remove(&key(end)) {
+            boundary.edges.push(edge);
+            boundary.vertices.push(start);
+            end = to;
+        }
+        loops.push(boundary);
+    }
+    loops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check a loop is closed and made of the boundary edges of a region.
+    fn check(boundary: &BoundaryLoop, region: &[Axial]) {
+        assert_eq!(boundary.edges.len(), boundary.vertices.len());
+        for (i, edge) in boundary.edges.iter().enumerate() {
+            let end = boundary.vertices[(i + 1) % boundary.vertices.len()];
+            assert_eq!(Edge::from_endpoints(boundary.vertices[i], end), Some(*edge));
+
+            let [a, b] = edge.adjacent_hexes();
+            assert_ne!(region.contains(&a), region.contains(&b));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(trace_boundary(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn single() {
+        let loops = trace_boundary([axial!(2, -1)]);
+        assert_eq!(loops.len(), 1);
+        check(&loops[0], &[axial!(2, -1)]);
+
+        // Walks the corners of the hex clockwise.
+        let mut edges = loops[0].edges.clone();
+        let mut expected = axial!(2, -1).edges().to_vec();
+        edges.sort_by_key(|edge| (edge.q, edge.r, edge.dir as i32));
+        expected.sort_by_key(|edge| (edge.q, edge.r, edge.dir as i32));
+        assert_eq!(edges, expected);
+
+        let start = axial!(2, -1)
+            .vertices()
+            .iter()
+            .position(|vertex| *vertex == loops[0].vertices[0])
+            .unwrap();
+        for (i, vertex) in loops[0].vertices.iter().enumerate() {
+            assert_eq!(*vertex, axial!(2, -1).vertices()[(start + i) % 6]);
+        }
+        assert!(!loops[0].is_hole());
+    }
+
+    #[test]
+    fn holes() {
+        // A hexagon of radius 2 with the center and one more hex removed.
+        let region: Vec<Axial> = axial!(0, 0)
+            .range(2)
+            .into_iter()
+            .filter(|coord| *coord != axial!(0, 0) && *coord != axial!(-2, 1))
+            .collect();
+        let loops = trace_boundary(region.iter().copied());
+        assert_eq!(loops.len(), 2);
+        for boundary in &loops {
+            check(boundary, &region);
+        }
+
+        let hole = loops.iter().find(|boundary| boundary.is_hole()).unwrap();
+        assert_eq!(hole.edges.len(), 6);
+        assert_eq!(hole.winding(), Winding::CounterClockwise);
+
+        let outer = loops.iter().find(|boundary| !boundary.is_hole()).unwrap();
+        assert_eq!(outer.edges.len(), 6 * 5 + 2);
+        assert_eq!(outer.winding(), Winding::Clockwise);
+    }
+
+    #[test]
+    fn far_from_origin() {
+        // A ring of hexes far from the origin has an outside and a hole.
+        let center = axial!(1_000_000_000, 1_000_000_000);
+        let region = center.neighbors();
+        let loops = trace_boundary(region);
+        assert_eq!(loops.len(), 2);
+        for boundary in &loops {
+            check(boundary, &region);
+        }
+        assert_eq!(
+            loops.iter().filter(|boundary| boundary.is_hole()).count(),
+            1
+        );
+
+        for coord in [center, axial!(i32::MAX / 2 + 10, 0)] {
+            assert_eq!(trace_boundary([coord])[0].winding(), Winding::Clockwise);
+        }
+    }
+
+    #[test]
+    fn disjoint() {
+        // Two regions, each with its own loop.
+        let region = [axial!(0, 0), axial!(1, 1)];
+        let loops = trace_boundary(region);
+        assert_eq!(loops.len(), 2);
+        for boundary in &loops {
+            check(boundary, &region);
+            assert_eq!(boundary.edges.len(), 6);
+            assert!(!boundary.is_hole());
+        }
+
+        // Duplicates are ignored.
+        assert_eq!(trace_boundary([axial!(0, 0), axial!(0, 0)]).len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn to_world() {
+        use crate::hex::grid::HexOrientation;
+        use assert_float_eq::*;
+
+        let converter = WSConverter {
+            size: 10.0,
+            orientation: HexOrientation::FlatTop,
+        };
+        let boundary = &trace_boundary([axial!(3, -2)])[0];
+        let polyline = boundary.to_world(&converter);
+        assert_eq!(polyline.len(), boundary.vertices.len() + 1);
+        assert_eq!(polyline.first(), polyline.last());
+
+        let center = converter.hex_to_world(axial!(3, -2));
+        for (x, y) in polyline {
+            assert_float_absolute_eq!(f64::hypot(x - center.0, y - center.1), 10.0);
+        }
+    }
+}
@@ -6,7 +6,42 @@ use super::{
     edge::{Edge, EdgeDirection},
     vertex::{Vertex, VertexDirection},
 };
-use crate::{core::transform::Transform, edge};
+use crate::{
+    core::{
+        autotile::HexMask,
+        coordinate::{Distance, Line, Neighbors, Range},
+        direction::{Direction, DirectionSet},
+        int::SignedInt,
+        parse::{self, ParseCoordError},
+        transform::Transform,
+    },
+    edge,
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::core::misc::Winding;
+
+mod checked;
+mod cube;
+mod diagonal;
+mod doubled;
+mod fractional;
+mod hierarchy;
+mod index;
+mod iter;
+mod label;
+mod offset;
+mod superhex;
+mod wrap;
+pub use cube::Cube;
+pub use diagonal::HexDiagonal;
+pub use doubled::{doubled, Doubled, DoubledLayout, DoubledLine, DoubledRange};
+pub use fractional::FracAxial;
+pub use hierarchy::HexCell;
+pub use iter::{AxialLine, AxialRange};
+pub use label::{HexLabel, LabelScheme, Labeling};
+pub use offset::{offset, Offset, OffsetLayout, OffsetLine, OffsetParity, OffsetRange};
+pub use superhex::SuperhexLayout;
 
 /// Axial based coordinates for hexagon grids.
 ///
@@ -14,17 +49,22 @@ use crate::{core::transform::Transform, edge};
 /// Only the q and r axes are stored, and we calculate the s when we need to.
 ///
 /// The coordinate system is similar but not fully analogous to cartesian 3D X, Y, Z.
+///
+/// Components are `i32` by default, any [`SignedInt`] can be used instead. Arithmetic, neighbors,
+/// distance, transforms, ranges, rings, lines and vertices work for every component type. Edges,
+/// rounding from floating point, symmetry, wrapping, indexing, the grid traits, e.g. [`Range`],
+/// and conversions to the other coordinate systems are `i32` only.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
-pub struct Axial {
+pub struct Axial<T = i32> {
     /// q (x) coordinate
-    pub q: i32,
+    pub q: T,
     /// r (y) coordinate
-    pub r: i32,
+    pub r: T,
 }
 
-impl From<Axial> for (i32, i32) {
-    fn from(value: Axial) -> Self {
+impl<T> From<Axial<T>> for (T, T) {
+    fn from(value: Axial<T>) -> Self {
         (value.q, value.r)
     }
 }
@@ -42,7 +82,7 @@ pub use axial;
 ///
 /// Positive q is the forward vector for a tile, meaning these directions are in relation to that.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
 pub enum HexDirection {
     /// Direction denoting positive q (x) axis
     Front,
@@ -87,7 +127,95 @@ impl From<HexDirection> for i32 {
     }
 }
 
+impl Direction for HexDirection {
+    const COUNT: u32 = 6;
+}
+
+/// A set of [`HexDirection`]s, see [`DirectionSet`].
+pub type HexDirectionSet = DirectionSet<HexDirection>;
+
 impl HexDirection {
+    /// Every direction, in CW order from [`HexDirection::Front`].
+    pub const ALL: [Self; 6] = [
+        HexDirection::Front,
+        HexDirection::FrontRight,
+        HexDirection::BackRight,
+        HexDirection::Back,
+        HexDirection::BackLeft,
+        HexDirection::FrontLeft,
+    ];
+
+    /// Iterate every direction, in CW order from [`HexDirection::Front`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+    ///
+    /// for dir in HexDirection::iter() {
+    ///     assert_eq!(axial!(0, 0).neighbor(dir).distance(axial!(0, 0)), 1);
+    /// }
+    /// ```
+    pub fn iter() -> impl ExactSizeIterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Get the direction pointing the opposite way.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::HexDirection;
+    ///
+    /// assert_eq!(HexDirection::FrontRight.opposite(), HexDirection::BackLeft);
+    /// ```
+    pub fn opposite(self) -> Self {
+        self.rotate(3)
+    }
+
+    /// Rotate the direction.
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    pub fn rotate(self, rot_dir: i32) -> Self {
+        Self::from(i32::from(self) + rot_dir)
+    }
+
+    /// Rotate the direction 60° CW.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::HexDirection;
+    ///
+    /// assert_eq!(HexDirection::FrontLeft.rotate_cw(), HexDirection::Front);
+    /// ```
+    pub fn rotate_cw(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotate the direction 60° CCW.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::HexDirection;
+    ///
+    /// assert_eq!(HexDirection::Front.rotate_ccw(), HexDirection::FrontLeft);
+    /// ```
+    pub fn rotate_ccw(self) -> Self {
+        self.rotate(-1)
+    }
+
+    /// Angle of the direction in degrees CW from [`HexDirection::Front`].
+    ///
+    /// Matches the angle produced by [`Axial::direction`] towards the neighbor in this direction.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::HexDirection;
+    ///
+    /// assert_eq!(HexDirection::BackRight.angle(), 120.0);
+    /// ```
+    pub fn angle(self) -> f64 {
+        60.0 * i32::from(self) as f64
+    }
+
     /// Converts a HexDirection to an [`Axial`] unit vector.
     ///
     /// # Example
@@ -114,6 +242,8 @@ impl HexDirection {
 }
 
 /// Represents the three axes of symmetry in hexagons.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
 pub enum Axes {
     /// q axis of a hexagonal grid
     Q,
@@ -123,7 +253,12 @@ pub enum Axes {
     S,
 }
 
-impl Axial {
+impl<T: SignedInt> Axial<T> {
+    /// Constructor for an axial coordinate.
+    pub const fn new(q: T, r: T) -> Self {
+        Self { q, r }
+    }
+
     /// Computes the S component.
     ///
     /// Follows the law of `q + r + s = 0`
@@ -134,7 +269,7 @@ impl Axial {
     /// // Computes the s component where q and r are 1.
     /// let s = axial!(1, 1).compute_s(); // s will be -2.
     /// ```
-    pub fn compute_s(&self) -> i32 {
+    pub fn compute_s(&self) -> T {
         -self.q - self.r
     }
 
@@ -180,44 +315,6 @@ impl Axial {
         axial!(self.compute_s(), self.q)
     }
 
-    /// Applies a transform matrix to this coordinate.
-    ///
-    /// Scale has no meaning with a point so we do not scale here.
-    ///
-    /// The order of applications is rotation then translation.
-    ///
-    /// # Example
-    /// ```
-    /// use gridava::hex::coordinate::{Axial, axial};
-    /// use gridava::core::transform::{Transform, transform, Vector2D, vector2d};
-    ///
-    /// let new_coord = axial!(2, 5).apply_transform(transform!(axial!(1, 1), 4));
-    /// ```
-    pub fn apply_transform(&self, transform: Transform<Self>) -> Self {
-        self.rotate(None, transform.rotation) + transform.translation
-    }
-
-    /// Make a vector from its components.
-    ///
-    /// Forms a vector from a location, magnitude and direction.
-    ///
-    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use gridava::hex::coordinate::{Axial, axial};
-    ///
-    /// // Create a unit vector (1, 0)
-    /// let unit_vector = axial!(0, 0).make_vector(1, 0);
-    ///
-    /// // Create a unit vector (0, 1)
-    /// let unit_vector = axial!(0, 0).make_vector(1, 1);
-    /// ```
-    pub fn make_vector(&self, magnitude: i32, rot_dir: i32) -> Self {
-        *self + HexDirection::from(rot_dir).to_movement_vector() * magnitude
-    }
-
     /// Get a neighbor coordinate given a direction.
     ///
     /// See [`HexDirection`] for a reference of directionality.
@@ -230,7 +327,8 @@ impl Axial {
     /// let coord = axial!(0, 0).neighbor(HexDirection::Front);
     /// ```
     pub fn neighbor(&self, direction: HexDirection) -> Self {
-        self.make_vector(1, direction.into())
+        let Axial { q, r } = direction.to_movement_vector();
+        axial!(self.q + T::from(q as i8), self.r + T::from(r as i8))
     }
 
     /// Get all the neighbors for this coordinate.
@@ -283,6 +381,111 @@ impl Axial {
         true
     }
 
+    /// Compute distance between two coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// // dist will be 2
+    /// let dist = axial!(0, 0).distance(axial!(2, 0));
+    ///
+    /// // dist will be 2
+    /// let dist = Axial::distance(&axial!(-1, 3), axial!(1, 1));
+    /// ```
+    pub fn distance(&self, b: Self) -> T {
+        // The largest of the three components, which does not overflow for distances that fit.
+        let vec = *self - b;
+        vec.q.abs().max(vec.r.abs()).max(vec.compute_s().abs())
+    }
+
+    /// Convert to another component type that can represent every value of this one.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let coord: Axial<i64> = axial!(3i16, -4).cast();
+    /// assert_eq!(coord, axial!(3i64, -4));
+    /// ```
+    pub fn cast<U: From<T>>(self) -> Axial<U> {
+        axial!(U::from(self.q), U::from(self.r))
+    }
+
+    /// Convert to another component type, checking each component fits.
+    ///
+    /// Produces [`None`] if a component is out of range of the new type.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(3i64, -4).try_cast::<i16>(), Some(axial!(3i16, -4)));
+    /// assert_eq!(axial!(1i64 << 40, 0).try_cast::<i32>(), None);
+    /// ```
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Axial<U>> {
+        Some(axial!(U::try_from(self.q).ok()?, U::try_from(self.r).ok()?))
+    }
+
+    /// Applies a transform matrix to this coordinate.
+    ///
+    /// Scale has no meaning with a point so we do not scale here.
+    ///
+    /// The order of applications is rotation then translation.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::core::transform::{Transform, transform, Vector2D, vector2d};
+    ///
+    /// let new_coord = axial!(2, 5).apply_transform(transform!(axial!(1, 1), 4));
+    /// ```
+    pub fn apply_transform(&self, transform: Transform<Self>) -> Self {
+        self.rotate(None, transform.rotation) + transform.translation
+    }
+
+    /// Make a vector from its components.
+    ///
+    /// Forms a vector from a location, magnitude and direction.
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// // Create a unit vector (1, 0)
+    /// let unit_vector = axial!(0, 0).make_vector(1, 0);
+    ///
+    /// // Create a unit vector (0, 1)
+    /// let unit_vector = axial!(0, 0).make_vector(1, 1);
+    /// ```
+    pub fn make_vector(&self, magnitude: T, rot_dir: i32) -> Self {
+        *self + Self::default().neighbor(HexDirection::from(rot_dir)) * magnitude
+    }
+
+    /// Compute a mask of the neighbors matching a predicate.
+    ///
+    /// Bit `i` of the mask is set if the neighbor in `HexDirection::from(i)` matches. See
+    /// [`AutotileRules`](crate::core::autotile::AutotileRules) for looking up tiles by mask.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// // Land lies in the Front and FrontRight directions.
+    /// let land = [axial!(1, 0), axial!(0, 1)];
+    /// let mask = axial!(0, 0).neighbor_mask(|coord| land.contains(&coord));
+    /// assert_eq!(mask.bits(), 0b000011);
+    /// ```
+    pub fn neighbor_mask<F>(&self, predicate: F) -> HexMask
+    where
+        F: FnMut(Self) -> bool,
+    {
+        HexMask::from_flags(self.neighbors().map(predicate))
+    }
+
     /// Generate a vertex
     ///
     /// Given an [`Axial`] coordinate and [`VertexDirection`] generate a [`Vertex`]
@@ -295,7 +498,7 @@ impl Axial {
     /// let vert = axial!(0, 0).vertex(VertexDirection::Up);
     /// assert_eq!(vert, Vertex::new(1, 0, 1));
     /// ```
-    pub fn vertex(&self, vert_dir: VertexDirection) -> Vertex {
+    pub fn vertex(&self, vert_dir: VertexDirection) -> Vertex<T> {
         (*self, vert_dir).into()
     }
 
@@ -311,7 +514,7 @@ impl Axial {
     /// let vertices = axial!(0,0).vertices();
     ///
     /// ```
-    pub fn vertices(&self) -> [Vertex; 6] {
+    pub fn vertices(&self) -> [Vertex<T>; 6] {
         [
             self.vertex(VertexDirection::Up),
             self.vertex(VertexDirection::UpRight),
@@ -322,29 +525,6 @@ impl Axial {
         ]
     }
 
-    /// Generates all 6 edges that are associated with this tile.
-    ///
-    /// See [`Vertex`].
-    ///
-    /// # Example
-    /// ```
-    /// use gridava::hex::edge::Edge;
-    /// use gridava::hex::coordinate::{Axial, axial};
-    ///
-    /// let edges = axial!(0, 0).edges();
-    ///
-    /// ```
-    pub fn edges(&self) -> [Edge; 6] {
-        [
-            edge!(self.q, self.r, EdgeDirection::NorthEast),
-            edge!(self.q + 1, self.r, EdgeDirection::West),
-            edge!(self.q, self.r + 1, EdgeDirection::NorthWest),
-            edge!(self.q - 1, self.r + 1, EdgeDirection::NorthEast),
-            edge!(self.q, self.r, EdgeDirection::West),
-            edge!(self.q, self.r, EdgeDirection::NorthWest),
-        ]
-    }
-
     /// Given two neighboring tiles produce the shared vertices.
     ///
     /// See [`Vertex`].
@@ -357,11 +537,10 @@ impl Axial {
     /// let vertices = axial!(0, 0).shared_vert_two(axial!(1, 0));
     ///
     /// ```
-    pub fn shared_vert_two(&self, b: Self) -> Option<[Vertex; 2]> {
+    pub fn shared_vert_two(&self, b: Self) -> Option<[Vertex<T>; 2]> {
         for i in 0..=5 {
             let dir = HexDirection::from(i);
-            let vec = dir.to_movement_vector();
-            if b == vec + *self {
+            if b == self.neighbor(dir) {
                 // We found the neighbor and its direction.
                 // Front is in the positive q direction here.
                 return match dir {
@@ -407,7 +586,7 @@ impl Axial {
     /// let vertices = axial!(0, 0).shared_vert_three(axial!(1, 0), axial!(0, 1));
     ///
     /// ```
-    pub fn shared_vert_three(&self, b: Self, c: Self) -> Option<Vertex> {
+    pub fn shared_vert_three(&self, b: Self, c: Self) -> Option<Vertex<T>> {
         let ab_vertices = self.shared_vert_two(b)?;
 
         // If c has vert 0 then we exit with 0
@@ -421,23 +600,6 @@ impl Axial {
         }
     }
 
-    /// Compute distance between two coordinates.
-    ///
-    /// # Example
-    /// ```
-    /// use gridava::hex::coordinate::{Axial, axial};
-    ///
-    /// // dist will be 2
-    /// let dist = axial!(0, 0).distance(axial!(2, 0));
-    ///
-    /// // dist will be 2
-    /// let dist = Axial::distance(&axial!(-1, 3), axial!(1, 1));
-    /// ```
-    pub fn distance(&self, b: Self) -> i32 {
-        let vec = *self - b;
-        (i32::abs(vec.q) + i32::abs(vec.q + vec.r) + i32::abs(vec.r)) / 2
-    }
-
     /// Direction to b from self.
     ///
     /// Outputs degrees from hex forward vector, +q, to the target b.
@@ -447,8 +609,8 @@ impl Axial {
         // direction to b from the pov of self
         let vec = b - *self;
 
-        let x = SQRT_3 * vec.q as f64 + SQRT_3 / 2.0 * vec.r as f64;
-        let y = 3.0 / 2.0 * vec.r as f64;
+        let x = SQRT_3 * vec.q.to_f64() + SQRT_3 / 2.0 * vec.r.to_f64();
+        let y = 3.0 / 2.0 * vec.r.to_f64();
         -y.atan2(-x).to_degrees() + 180.0
     }
 
@@ -462,24 +624,49 @@ impl Axial {
         // direction to b from the pov of self
         let vec = b - *self;
 
-        let x = SQRT_3 * vec.q as f64 + SQRT_3 / 2.0 * vec.r as f64;
-        let y = 3.0 / 2.0 * vec.r as f64;
+        let x = SQRT_3 * vec.q.to_f64() + SQRT_3 / 2.0 * vec.r.to_f64();
+        let y = 3.0 / 2.0 * vec.r.to_f64();
         atan2(-y, -x).to_degrees() + 180.0
     }
 
-    /// Rounds a floating hex coordinate to an integer coordinate.
+    /// The direction that best approximates the vector to b from self.
     ///
-    /// This algorithm is based on the round function by Jacob Rus
-    /// <https://observablehq.com/@jrus/hexround>
+    /// Produces the direction closest in angle to [`Axial::direction`], or [`None`] if b is self.
+    /// A vector exactly between two directions resolves to the CW one of the two.
     ///
     /// # Example
     /// ```
-    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
     ///
-    /// let coord = Axial::round((1.6, 3.2));
+    /// assert_eq!(axial!(0, 0).nearest_direction(axial!(3, -1)), Some(HexDirection::Front));
+    /// assert_eq!(axial!(0, 0).nearest_direction(axial!(-1, 3)), Some(HexDirection::FrontRight));
+    /// assert_eq!(axial!(0, 0).nearest_direction(axial!(0, 0)), None);
     /// ```
+    pub fn nearest_direction(&self, b: Self) -> Option<HexDirection> {
+        if *self == b {
+            return None;
+        }
+
+        // Cube coordinates are proportional to the plane, so the direction with the largest dot
+        // product with the vector is the closest in angle. Widening keeps the products exact.
+        let widen = |coord: Self| axial!(coord.q.to_i128(), coord.r.to_i128());
+        let vec = widen(b) - widen(*self);
+        let cube = [vec.q, vec.r, vec.compute_s()];
+        let dot = |dir: HexDirection| {
+            let unit = dir.to_movement_vector().cast::<i128>();
+            cube[0] * unit.q + cube[1] * unit.r + cube[2] * unit.compute_s()
+        };
+
+        let best = HexDirection::iter().max_by_key(|dir| (dot(*dir), Reverse(i32::from(*dir))))?;
+        match dot(best.rotate_cw()) == dot(best) {
+            true => Some(best.rotate_cw()),
+            false => Some(best),
+        }
+    }
+
+    // Rounds a floating hex coordinate to any component type, see `Axial::round`.
     #[cfg(feature = "std")]
-    pub fn round(coord_f: (f64, f64)) -> Self {
+    fn round_frac(coord_f: (f64, f64)) -> Self {
         let q_grid = coord_f.0.round();
         let r_grid = coord_f.1.round();
 
@@ -488,26 +675,16 @@ impl Axial {
 
         if q_rem.abs() >= r_rem.abs() {
             let q = q_grid + f64::round(q_rem + 0.5 * r_rem);
-            axial!(q as i32, r_grid as i32)
+            axial!(T::from_f64(q), T::from_f64(r_grid))
         } else {
             let r = r_grid + f64::round(r_rem + 0.5 * q_rem);
-            axial!(q_grid as i32, r as i32)
+            axial!(T::from_f64(q_grid), T::from_f64(r))
         }
     }
 
-    /// Rounds a floating hex coordinate to an integer coordinate.
-    ///
-    /// This algorithm is based on the round function by Jacob Rus
-    /// <https://observablehq.com/@jrus/hexround>
-    ///
-    /// # Example
-    /// ```
-    /// use gridava::hex::coordinate::{Axial, axial};
-    ///
-    /// let coord = Axial::round((1.6, 3.2));
-    /// ```
+    // Rounds a floating hex coordinate to any component type, see `Axial::round`.
     #[cfg(not(feature = "std"))]
-    pub fn round(coord_f: (f64, f64)) -> Self {
+    fn round_frac(coord_f: (f64, f64)) -> Self {
         use crate::lib::{fabs, round};
 
         let q_grid = round(coord_f.0);
@@ -518,10 +695,10 @@ impl Axial {
 
         if fabs(q_rem) >= fabs(r_rem) {
             let q = q_grid + round(q_rem + 0.5 * r_rem);
-            axial!(q as i32, rgrid as i32)
+            axial!(T::from_f64(q), T::from_f64(r_grid))
         } else {
             let r = r_grid + round(r_rem + 0.5 * q_rem);
-            axial!(qgrid as i32, r as i32)
+            axial!(T::from_f64(q_grid), T::from_f64(r))
         }
     }
 
@@ -529,6 +706,8 @@ impl Axial {
     ///
     /// Given time `t`, or a percentage, calculate an in between value along the line.
     ///
+    /// The result is rounded to a tile, see [`FracAxial::lerp`] to keep the fractional position.
+    ///
     /// # Example
     /// ```
     /// use gridava::hex::coordinate::{Axial, axial};
@@ -537,9 +716,24 @@ impl Axial {
     /// let coord = axial!(0, 0).lerp(axial!(3, 0), 0.3);
     /// ```
     pub fn lerp(&self, b: Self, t: f64) -> Self {
-        let q = crate::core::misc::lerp(self.q as f64, b.q as f64, t);
-        let r = crate::core::misc::lerp(self.r as f64, b.r as f64, t);
-        Self::round((q, r))
+        let frac = |coord: Self| FracAxial::new(coord.q.to_f64(), coord.r.to_f64());
+        Self::round_frac(frac(*self).lerp(frac(b), t).into())
+    }
+
+    /// Lazily calculate the coordinates that form a line between two points.
+    ///
+    /// Does not allocate, see [`Axial::line`] for a collected version.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let mut coords = axial!(0, 0).line_iter(axial!(2, 0));
+    /// assert_eq!(coords.len(), 3);
+    /// assert_eq!(coords.next(), Some(axial!(0, 0)));
+    /// ```
+    pub fn line_iter(&self, b: Self) -> AxialLine<T> {
+        AxialLine::new(*self, b)
     }
 
     /// Calculate all the coordinates that form a line between two points.
@@ -553,16 +747,22 @@ impl Axial {
     /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn line(&self, b: Self) -> Vec<Self> {
-        let dist = self.distance(b);
-        let mut ret = vec![];
-
-        let constant = 1.0 / dist as f64;
-
-        for i in 0..=dist {
-            ret.push(self.lerp(b, constant * i as f64));
-        }
+        self.line_iter(b).collect()
+    }
 
-        ret
+    /// Lazily calculate all the coordinates within a range.
+    ///
+    /// Does not allocate, see [`Axial::range`] for a collected version.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// // self and its 6 neighbors
+    /// assert_eq!(axial!(0, 0).range_iter(1).len(), 7);
+    /// ```
+    pub fn range_iter(&self, range: T) -> AxialRange<T> {
+        AxialRange::new(*self, range)
     }
 
     /// Calculate all the coordinates within a range.
@@ -575,18 +775,163 @@ impl Axial {
     /// let coords = axial!(0, 0).range(1);
     /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
-    pub fn range(&self, range: i32) -> Vec<Self> {
-        let mut ret = vec![];
+    pub fn range(&self, range: T) -> Vec<Self> {
+        self.range_iter(range).collect()
+    }
+
+    /// Calculate all the coordinates at exactly a distance.
+    ///
+    /// The ring begins at the coordinate `radius` tiles away in the `start` direction and
+    /// walks around self according to `winding`. A radius of 0 produces self.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::misc::Winding;
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+    ///
+    /// let coords = axial!(0, 0).ring(1, HexDirection::Front, Winding::Clockwise);
+    /// assert_eq!(coords, axial!(0, 0).neighbors());
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn ring(&self, radius: T, start: HexDirection, winding: Winding) -> Vec<Self> {
+        let mut ret = Vec::new();
+        if radius <= T::ZERO {
+            if radius == T::ZERO {
+                ret.push(*self);
+            }
+            return ret;
+        }
 
-        for q in -range..=range {
-            for r in i32::max(-range, -q - range)..=i32::min(range, -q + range) {
-                ret.push(*self + axial!(q, r));
+        let start = i32::from(start);
+        let sign = match winding {
+            Winding::Clockwise => 1,
+            Winding::CounterClockwise => -1,
+        };
+
+        ret.reserve(usize::try_from(radius.to_i128().saturating_mul(6)).unwrap_or(usize::MAX));
+        let mut current = self.make_vector(radius, start);
+
+        // Walking from one corner of the ring to the next is two rotations from the corner's direction.
+        for side in 0..6 {
+            let dir = start + sign * (side + 2);
+            let mut step = T::ZERO;
+            while step < radius {
+                ret.push(current);
+                current = current.neighbor(HexDirection::from(dir));
+                step += T::ONE;
             }
         }
 
         ret
     }
 
+    /// Calculate all the coordinates within a range, ordered from self outward.
+    ///
+    /// Each ring is produced as in [`Axial::ring`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::misc::Winding;
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+    ///
+    /// let coords = axial!(0, 0).spiral(2, HexDirection::Front, Winding::CounterClockwise);
+    /// assert_eq!(coords.len(), 19);
+    /// assert_eq!(coords[0], axial!(0, 0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn spiral(&self, radius: T, start: HexDirection, winding: Winding) -> Vec<Self> {
+        let mut ret = Vec::new();
+        let mut ring = T::ZERO;
+        while ring <= radius {
+            ret.extend(self.ring(ring, start, winding));
+            ring += T::ONE;
+        }
+        ret
+    }
+
+    // Clockwise angle from `dir` to self as seen from center, measured along the hex ring so a
+    // sextant spans `distance` units. Returns (angle, distance) with angle in 0..6 * distance.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn sextant_angle(&self, center: Self, dir: HexDirection) -> (T, T) {
+        let local = (*self - center).rotate(None, -i32::from(dir));
+        let dist = local.distance(Self::default());
+        let (q, r, s) = (local.q, local.r, local.compute_s());
+
+        // Mirror the counter clockwise half onto the clockwise half across the dir axis.
+        let (q, r, s, mirrored) = match r < T::ZERO {
+            true => (-s, -r, -q, true),
+            false => (q, r, s, false),
+        };
+
+        let angle = if q >= T::ZERO {
+            r
+        } else if s <= T::ZERO {
+            dist - q
+        } else {
+            T::from(2) * dist + s
+        };
+
+        match mirrored {
+            true => (T::from(6) * dist - angle, dist),
+            false => (angle, dist),
+        }
+    }
+
+    /// Calculate all the coordinates within a cone.
+    ///
+    /// The cone opens from self facing `dir`, spanning `width` sextants (60° each) split evenly to
+    /// either side of `dir`, out to `radius`. Coordinates on the cone's boundary are included.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+    ///
+    /// // A 60° cone of radius 2 facing the positive q axis.
+    /// let coords = axial!(0, 0).cone(HexDirection::Front, 1, 2);
+    /// assert_eq!(
+    ///     coords,
+    ///     vec![axial!(0, 0), axial!(1, 0), axial!(1, 1), axial!(2, -1), axial!(2, 0)]
+    /// );
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn cone(&self, dir: HexDirection, width: u32, radius: T) -> Vec<Self> {
+        let dir = i32::from(dir);
+        let width = T::from(width.min(6) as i8);
+
+        self.range_iter(radius)
+            .filter(|coord| {
+                let (angle, dist) = coord.sextant_angle(*self, HexDirection::from(dir));
+                T::from(2) * angle.min(T::from(6) * dist - angle) <= width * dist
+            })
+            .collect()
+    }
+
+    /// Calculate all the coordinates within a wedge.
+    ///
+    /// The wedge opens from self starting along `dir` and sweeps clockwise `width` sextants (60°
+    /// each), out to `radius`. Coordinates on the wedge's boundary are included.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+    ///
+    /// // The sextant between the Front and FrontRight directions.
+    /// let coords = axial!(0, 0).wedge(HexDirection::Front, 1, 1);
+    /// assert_eq!(coords, vec![axial!(0, 0), axial!(0, 1), axial!(1, 0)]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn wedge(&self, dir: HexDirection, width: u32, radius: T) -> Vec<Self> {
+        let dir = i32::from(dir);
+        let width = T::from(width.min(6) as i8);
+
+        self.range_iter(radius)
+            .filter(|coord| {
+                let (angle, dist) = coord.sextant_angle(*self, HexDirection::from(dir));
+                angle <= width * dist
+            })
+            .collect()
+    }
+
     // center: Option<Self> denotes a point to reflect about. If provided None, coordinate (0,0) will be used.
     /// Reflect a coordinate across an axis of symmetry.
     ///
@@ -609,27 +954,17 @@ impl Axial {
     /// let reflected = axial!(0, 0).reflect(Some(axial!(0, 1)), Axes::Q);
     /// ```
     pub fn reflect(&self, center: Option<Self>, axes: Axes) -> Self {
-        let center = center.unwrap_or(axial!(0, 0));
+        let center = center.unwrap_or_default();
+        let vec = *self - center;
+        let (q, r, s) = (vec.q, vec.r, vec.compute_s());
 
-        let centered_coord = *self - center;
-
-        match axes {
-            Axes::Q => axial!(centered_coord.q, centered_coord.compute_s()) + center,
-            Axes::R => axial!(centered_coord.compute_s(), centered_coord.r) + center,
-            Axes::S => axial!(centered_coord.r, centered_coord.q) + center,
-        }
-    }
+        let reflected = match axes {
+            Axes::Q => axial!(q, s),
+            Axes::R => axial!(s, r),
+            Axes::S => axial!(r, q),
+        };
 
-    pub(self) fn rotate_recursive(&self, iter: usize, cw: bool) -> Self {
-        if iter == 0 {
-            *self
-        } else {
-            let input = match cw {
-                true => -self.swizzle_l(),
-                false => -self.swizzle_r(),
-            };
-            input.rotate_recursive(iter - 1, cw)
-        }
+        reflected + center
     }
 
     /// Rotate a coordinate.
@@ -649,70 +984,160 @@ impl Axial {
     /// let coord = axial!(1, 0).rotate(Some(axial!(2, 0)), 1);
     /// ```
     pub fn rotate(&self, center: Option<Self>, rot_dir: i32) -> Self {
-        let center = center.unwrap_or(axial!(0, 0));
+        let center = center.unwrap_or_default();
+        let vec = *self - center;
+        let (q, r, s) = (vec.q, vec.r, vec.compute_s());
+
+        // Every 60 degree CW rotation shifts the components right and negates them.
+        let rotated = match rot_dir.rem_euclid(6) {
+            0 => axial!(q, r),
+            1 => axial!(-r, -s),
+            2 => axial!(s, q),
+            3 => axial!(-q, -r),
+            4 => axial!(r, s),
+            5 => axial!(-s, -q),
+            _ => unreachable!(), // should never reach
+        };
 
-        let centered_coord = *self - center;
+        rotated + center
+    }
+}
 
-        if rot_dir < 0 {
-            // negative, CCW
-            centered_coord.rotate_recursive(rot_dir.rem(6).unsigned_abs() as usize, false) + center
-        } else {
-            // positive, CW
-            centered_coord.rotate_recursive(rot_dir.rem(6).unsigned_abs() as usize, true) + center
-        }
+impl Axial {
+    /// Get the edge shared with the neighbor in a direction.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// assert_eq!(axial!(2, 1).edge(HexDirection::Back), edge!(2, 1, EdgeDirection::West));
+    /// assert_eq!(axial!(2, 1).edge(HexDirection::Front), edge!(3, 1, EdgeDirection::West));
+    /// ```
+    pub fn edge(&self, dir: HexDirection) -> Edge {
+        let offset = Edge::from(dir);
+        edge!(self.q + offset.q, self.r + offset.r, offset.dir)
+    }
+
+    /// Generates all 6 edges that are associated with this tile.
+    ///
+    /// See [`Vertex`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::edge::Edge;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let edges = axial!(0, 0).edges();
+    ///
+    /// ```
+    pub fn edges(&self) -> [Edge; 6] {
+        [
+            edge!(self.q, self.r, EdgeDirection::NorthEast),
+            edge!(self.q + 1, self.r, EdgeDirection::West),
+            edge!(self.q, self.r + 1, EdgeDirection::NorthWest),
+            edge!(self.q - 1, self.r + 1, EdgeDirection::NorthEast),
+            edge!(self.q, self.r, EdgeDirection::West),
+            edge!(self.q, self.r, EdgeDirection::NorthWest),
+        ]
+    }
+
+    /// Rounds a floating hex coordinate to an integer coordinate.
+    ///
+    /// This algorithm is based on the round function by Jacob Rus
+    /// <https://observablehq.com/@jrus/hexround>
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let coord = Axial::round((1.6, 3.2));
+    /// ```
+    pub fn round(coord_f: (f64, f64)) -> Self {
+        Self::round_frac(coord_f)
+    }
+}
+
+impl Neighbors for Axial {
+    type NeighborIter = array::IntoIter<Self, 6>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        Axial::neighbors(self).into_iter()
+    }
+}
+
+impl Distance for Axial {
+    fn distance(&self, b: Self) -> u32 {
+        Axial::distance(self, b).unsigned_abs()
+    }
+}
+
+impl Range for Axial {
+    type RangeIter = AxialRange;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        Axial::range_iter(self, range)
+    }
+}
+
+impl Line for Axial {
+    type LineIter = AxialLine;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        Axial::line_iter(self, b)
     }
 }
 
-impl Add for Axial {
-    type Output = Axial;
+impl<T: SignedInt> Add for Axial<T> {
+    type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
         axial!(self.q + rhs.q, self.r + rhs.r)
     }
 }
 
-impl AddAssign for Axial {
+impl<T: SignedInt> AddAssign for Axial<T> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
-impl Sub for Axial {
-    type Output = Axial;
+impl<T: SignedInt> Sub for Axial<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
         axial!(self.q - rhs.q, self.r - rhs.r)
     }
 }
 
-impl SubAssign for Axial {
+impl<T: SignedInt> SubAssign for Axial<T> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
 // TODO: determine if we wish to return floats to handle potential truncation
-impl Div<i32> for Axial {
-    type Output = Axial;
+impl<T: SignedInt> Div<T> for Axial<T> {
+    type Output = Self;
 
-    fn div(self, rhs: i32) -> Self::Output {
+    fn div(self, rhs: T) -> Self::Output {
         axial!(self.q / rhs, self.r / rhs)
     }
 }
 
-impl<T> Mul<T> for Axial
+impl<I, T> Mul<T> for Axial<I>
 where
-    i32: Mul<T, Output = i32>,
+    I: Mul<T, Output = I>,
     T: Copy,
 {
-    type Output = Axial;
+    type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
         axial!(self.q * rhs, self.r * rhs)
     }
 }
 
-impl Neg for Axial {
+impl<T: SignedInt> Neg for Axial<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -720,6 +1145,22 @@ impl Neg for Axial {
     }
 }
 
+impl<T: Display> Display for Axial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Axial({}, {})", self.q, self.r)
+    }
+}
+
+/// Parses the form produced by [`Display`], e.g. `Axial(1, -2)`.
+impl<T: FromStr> FromStr for Axial<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [q, r] = parse::components(s, "Axial")?;
+        Ok(axial!(parse::component(q)?, parse::component(r)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use assert_float_eq::*;
@@ -799,6 +1240,103 @@ mod tests {
         assert!(!axial!(0, 0).are_neighbors(&[axial!(1, 1)]));
     }
 
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn generic_queries() {
+        // Every query agrees with the i32 version once widened.
+        let wide = |coord: Axial| coord.cast::<i64>();
+        let center = axial!(1, -2);
+        for coord in center.range_iter(3) {
+            assert_eq!(
+                wide(coord).rotate(Some(wide(center)), 2),
+                wide(coord.rotate(Some(center), 2))
+            );
+            for axes in [Axes::Q, Axes::R, Axes::S] {
+                assert_eq!(
+                    wide(coord).reflect(None, axes),
+                    wide(coord.reflect(None, axes))
+                );
+            }
+            assert_eq!(
+                wide(coord).nearest_direction(wide(center)),
+                coord.nearest_direction(center)
+            );
+            assert_eq!(wide(coord).direction(wide(center)), coord.direction(center));
+            assert_eq!(
+                wide(coord).line(wide(center)),
+                coord.line(center).into_iter().map(wide).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                wide(coord).vertices(),
+                coord.vertices().map(|vertex| vertex.cast::<i64>())
+            );
+        }
+        assert!(wide(center)
+            .range_iter(3)
+            .eq(center.range_iter(3).map(wide)));
+        assert!(wide(center)
+            .spiral(2, HexDirection::Back, Winding::Clockwise)
+            .into_iter()
+            .eq(center
+                .spiral(2, HexDirection::Back, Winding::Clockwise)
+                .into_iter()
+                .map(wide)));
+        assert!(wide(center)
+            .cone(HexDirection::Front, 2, 3)
+            .into_iter()
+            .eq(center.cone(HexDirection::Front, 2, 3).into_iter().map(wide)));
+
+        // Beyond the range of i32.
+        let far = axial!(1i64 << 40, -(1i64 << 41));
+        assert_eq!(
+            far.ring(1, HexDirection::Front, Winding::Clockwise),
+            far.neighbors()
+        );
+        assert_eq!(far.rotate(Some(far), 1), far);
+        assert_eq!(far.line_iter(far + axial!(3, 0)).len(), 4);
+        assert_eq!(Axial::<i8>::new(0, 0).range_iter(2).len(), 19);
+    }
+
+    #[test]
+    fn generic_width() {
+        // Beyond the range of i32, where the distance would overflow.
+        let far = axial!(i64::from(i32::MAX), i64::from(i32::MIN));
+        assert_eq!(far.distance(axial!(0, 0)), i64::from(i32::MAX) + 1);
+        assert_eq!((far * 2).compute_s(), 2);
+        assert_eq!(far.neighbor(HexDirection::BackLeft), far - axial!(0, 1));
+
+        let small = Axial::<i16>::new(3, -2);
+        assert_eq!(small.neighbors().len(), 6);
+        assert!(small.are_neighbors(&[axial!(4, -2), axial!(3, -1)]));
+        assert_eq!(small.swizzle_l().swizzle_r(), small);
+        assert_eq!(small.distance(-small), 6);
+
+        assert_eq!(small.cast::<i32>(), axial!(3, -2));
+        assert_eq!(small.cast::<i64>().try_cast::<i16>(), Some(small));
+        assert_eq!((far * 2).try_cast::<i32>(), None);
+        assert_eq!(far.try_cast::<i16>(), None);
+        assert_eq!(
+            (far / 2).try_cast::<i32>(),
+            Some(axial!(i32::MAX / 2, i32::MIN / 2))
+        );
+    }
+
+    #[test]
+    fn neighbor_mask() {
+        let land = [axial!(2, 0), axial!(1, 1), axial!(0, 0)];
+        let mask = axial!(1, 0).neighbor_mask(|coord| land.contains(&coord));
+        assert_eq!(mask.bits(), 0b001011);
+
+        // Rotating the terrain about the tile rotates the mask.
+        for rot in 0..6 {
+            let rotated = land.map(|coord| coord.rotate(Some(axial!(1, 0)), rot));
+            assert_eq!(
+                axial!(1, 0).neighbor_mask(|coord| rotated.contains(&coord)),
+                mask.rotate(rot)
+            );
+        }
+    }
+
     #[test]
     fn add() {
         assert_eq!(axial!(4, 2) + axial!(1, 3), axial!(5, 5));
@@ -876,6 +1414,7 @@ mod tests {
         assert_eq!(axial!(-1, -1).distance(axial!(1, -1)), 2);
         assert_eq!(axial!(-1, -1).distance(axial!(-1, 1)), 2);
         assert_eq!(axial!(-1, -1).distance(axial!(2, 1)), 5);
+        assert_eq!(axial!(0, 0).distance(axial!(i32::MAX, 0)), i32::MAX);
     }
 
     #[test]
@@ -897,6 +1436,62 @@ mod tests {
         assert_f64_near!(axial!(0, 0).direction(axial!(2, -2)), 300.0);
     }
 
+    #[test]
+    fn edge() {
+        for dir in HexDirection::iter() {
+            let coord = axial!(3, -2);
+            let edge = coord.edge(dir);
+            assert!(coord.edges().contains(&edge));
+            assert!(edge.adjacent_hexes().contains(&coord.neighbor(dir)));
+            assert_eq!(coord.neighbor(dir).edge(dir.opposite()), edge);
+        }
+    }
+
+    #[test]
+    fn hex_direction() {
+        assert_eq!(HexDirection::iter().len(), 6);
+        for (index, dir) in HexDirection::iter().enumerate() {
+            assert_eq!(i32::from(dir), index as i32);
+            assert_eq!(
+                dir.opposite().to_movement_vector(),
+                -dir.to_movement_vector()
+            );
+            assert_eq!(dir.rotate_cw().rotate_ccw(), dir);
+            assert_eq!(
+                dir.rotate_cw().to_movement_vector(),
+                dir.to_movement_vector().rotate(None, 1)
+            );
+            assert_eq!(dir.rotate(-7), dir.rotate_ccw());
+            assert_f64_near!(
+                dir.angle(),
+                axial!(0, 0).direction(dir.to_movement_vector())
+            );
+        }
+    }
+
+    #[test]
+    fn nearest_direction() {
+        let center = axial!(2, -3);
+        for dir in HexDirection::iter() {
+            let vec = dir.to_movement_vector();
+            assert_eq!(center.nearest_direction(center + vec * 5), Some(dir));
+
+            // Within 30° of the direction.
+            let near = center + vec * 4 + dir.rotate_cw().to_movement_vector();
+            assert_eq!(center.nearest_direction(near), Some(dir));
+
+            // Exactly between two directions.
+            let between = center + vec + dir.rotate_cw().to_movement_vector();
+            assert_eq!(center.nearest_direction(between), Some(dir.rotate_cw()));
+        }
+
+        assert_eq!(center.nearest_direction(center), None);
+        assert_eq!(
+            axial!(i32::MIN, i32::MAX).nearest_direction(axial!(i32::MAX, i32::MIN)),
+            Some(HexDirection::FrontLeft)
+        );
+    }
+
     #[test]
     fn lerp() {
         assert_eq!(axial!(-1, -1).lerp(axial!(9, 19), -0.25), axial!(-3, -6));
@@ -1011,6 +1606,165 @@ mod tests {
         );
     }
 
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn ring() {
+        assert_eq!(
+            axial!(1, 1).ring(0, HexDirection::Front, Winding::Clockwise),
+            vec![axial!(1, 1)]
+        );
+        assert!(axial!(1, 1)
+            .ring(-1, HexDirection::Front, Winding::Clockwise)
+            .is_empty());
+
+        assert_eq!(
+            axial!(0, 0).ring(1, HexDirection::Front, Winding::Clockwise),
+            axial!(0, 0).neighbors()
+        );
+        assert_eq!(
+            axial!(0, 0).ring(1, HexDirection::Back, Winding::CounterClockwise),
+            vec![
+                axial!(-1, 0),
+                axial!(-1, 1),
+                axial!(0, 1),
+                axial!(1, 0),
+                axial!(1, -1),
+                axial!(0, -1),
+            ]
+        );
+        assert_eq!(
+            axial!(0, 0).ring(2, HexDirection::Front, Winding::Clockwise),
+            vec![
+                axial!(2, 0),
+                axial!(1, 1),
+                axial!(0, 2),
+                axial!(-1, 2),
+                axial!(-2, 2),
+                axial!(-2, 1),
+                axial!(-2, 0),
+                axial!(-1, -1),
+                axial!(0, -2),
+                axial!(1, -2),
+                axial!(2, -2),
+                axial!(2, -1),
+            ]
+        );
+
+        // Every coordinate is at the exact distance and adjacent to the next.
+        for winding in [Winding::Clockwise, Winding::CounterClockwise] {
+            for start in 0..6 {
+                let center = axial!(3, -2);
+                let ring = center.ring(4, HexDirection::from(start), winding);
+                assert_eq!(ring.len(), 24);
+                assert_eq!(ring[0], center.make_vector(4, start));
+                for (i, coord) in ring.iter().enumerate() {
+                    assert_eq!(center.distance(*coord), 4);
+                    assert!(coord.are_neighbors(&[ring[(i + 1) % ring.len()]]));
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn spiral() {
+        assert_eq!(
+            axial!(0, 0).spiral(0, HexDirection::Front, Winding::Clockwise),
+            vec![axial!(0, 0)]
+        );
+
+        let spiral = axial!(2, 1).spiral(3, HexDirection::BackLeft, Winding::CounterClockwise);
+        let mut sorted = spiral.clone();
+        let mut range = axial!(2, 1).range(3);
+        sorted.sort_by_key(|c| (c.q, c.r));
+        range.sort_by_key(|c| (c.q, c.r));
+        assert_eq!(sorted, range);
+
+        // Ordered by distance from the center.
+        assert!(spiral
+            .windows(2)
+            .all(|w| axial!(2, 1).distance(w[0]) <= axial!(2, 1).distance(w[1])));
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn cone() {
+        assert_eq!(
+            axial!(0, 0).cone(HexDirection::Front, 1, 1),
+            vec![axial!(0, 0), axial!(1, 0)]
+        );
+        assert_eq!(
+            axial!(0, 0).cone(HexDirection::Front, 2, 1),
+            vec![axial!(0, 0), axial!(0, 1), axial!(1, -1), axial!(1, 0)]
+        );
+        assert_eq!(
+            axial!(1, 1).cone(HexDirection::Back, 1, 2),
+            vec![
+                axial!(-1, 1),
+                axial!(-1, 2),
+                axial!(0, 0),
+                axial!(0, 1),
+                axial!(1, 1)
+            ]
+        );
+
+        // Full width covers the whole range.
+        assert_eq!(
+            axial!(2, -3).cone(HexDirection::BackLeft, 6, 3),
+            axial!(2, -3).range(3)
+        );
+        assert_eq!(
+            axial!(2, -3).cone(HexDirection::BackLeft, 9, 3),
+            axial!(2, -3).range(3)
+        );
+
+        // Zero width is a ray.
+        assert_eq!(
+            axial!(0, 0).cone(HexDirection::FrontRight, 0, 3),
+            vec![axial!(0, 0), axial!(0, 1), axial!(0, 2), axial!(0, 3)]
+        );
+
+        // Rotating the direction rotates the cone.
+        for dir in 0..6 {
+            let mut rotated = axial!(0, 0)
+                .cone(HexDirection::Front, 3, 4)
+                .into_iter()
+                .map(|coord| coord.rotate(None, dir))
+                .collect::<Vec<_>>();
+            rotated.sort_by_key(|coord| (coord.q, coord.r));
+            assert_eq!(axial!(0, 0).cone(HexDirection::from(dir), 3, 4), rotated);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn wedge() {
+        assert_eq!(
+            axial!(0, 0).wedge(HexDirection::Front, 1, 1),
+            vec![axial!(0, 0), axial!(0, 1), axial!(1, 0)]
+        );
+        assert_eq!(
+            axial!(0, 0).wedge(HexDirection::FrontLeft, 2, 1),
+            vec![axial!(0, 0), axial!(0, 1), axial!(1, -1), axial!(1, 0)]
+        );
+        assert_eq!(
+            axial!(0, 0).wedge(HexDirection::Front, 0, 2),
+            vec![axial!(0, 0), axial!(1, 0), axial!(2, 0)]
+        );
+        assert_eq!(
+            axial!(1, 1).wedge(HexDirection::Back, 6, 2),
+            axial!(1, 1).range(2)
+        );
+
+        // Adjacent wedges share their boundary rays, so the rays and the center are counted more
+        // than once.
+        let sextants = (0..6)
+            .map(|dir| axial!(0, 0).wedge(HexDirection::from(dir), 1, 3).len())
+            .sum::<usize>();
+        // 6 shared rays of 3 tiles plus 5 extra copies of the center.
+        assert_eq!(sextants, axial!(0, 0).range(3).len() + 6 * 3 + 5);
+    }
+
     #[test]
     fn reflect() {
         assert_eq!(axial!(-1, 1).reflect(None, Axes::Q), axial!(-1, 0));
@@ -1152,4 +1906,24 @@ mod tests {
             .shared_vert_three(axial!(1, 0), axial!(3, 3))
             .is_none());
     }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(format!("{}", axial!(1, -2)), "Axial(1, -2)");
+        assert_eq!("Axial(1, -2)".parse(), Ok(axial!(1, -2)));
+        assert_eq!(" Axial( 3 ,4 ) ".parse(), Ok(axial!(3, 4)));
+        assert_eq!(
+            "Axial(300, 0)".parse::<Axial<i8>>(),
+            Err(ParseCoordError::Component)
+        );
+        assert_eq!(
+            "Axial(1, -2, 1)".parse::<Axial>(),
+            Err(ParseCoordError::Format)
+        );
+        assert_eq!("(1, -2)".parse::<Axial>(), Err(ParseCoordError::Format));
+
+        for coord in axial!(0, 0).range_iter(2) {
+            assert_eq!(format!("{coord}").parse(), Ok(coord));
+        }
+    }
 }
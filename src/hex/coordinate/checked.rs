@@ -0,0 +1,331 @@
+//! Overflow checked and saturating coordinate arithmetic.
+//!
+//! The operators on [`Axial`] wrap around in release builds, these variants either report the
+//! overflow or clamp to the bounds of the component type. Useful when coordinates come from
+//! untrusted input, e.g. network clients.
+
+use crate::core::int::SignedInt;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::lib::*;
+
+use super::{axial, Axial, AxialLine, AxialRange, HexDirection};
+
+impl<T: SignedInt> Axial<T> {
+    /// Add two coordinates, [`None`] if a component overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(1, 2).checked_add(axial!(3, 4)), Some(axial!(4, 6)));
+    /// assert_eq!(axial!(i32::MAX, 0).checked_add(axial!(1, 0)), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(axial!(
+            self.q.checked_add(rhs.q)?,
+            self.r.checked_add(rhs.r)?
+        ))
+    }
+
+    /// Subtract two coordinates, [`None`] if a component overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(axial!(
+            self.q.checked_sub(rhs.q)?,
+            self.r.checked_sub(rhs.r)?
+        ))
+    }
+
+    /// Scale a coordinate, [`None`] if a component overflows.
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        Some(axial!(self.q.checked_mul(rhs)?, self.r.checked_mul(rhs)?))
+    }
+
+    /// Divide a coordinate, [`None`] if a component overflows or `rhs` is zero.
+    pub fn checked_div(self, rhs: T) -> Option<Self> {
+        Some(axial!(self.q.checked_div(rhs)?, self.r.checked_div(rhs)?))
+    }
+
+    /// Negate a coordinate, [`None`] if a component overflows.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(axial!(self.q.checked_neg()?, self.r.checked_neg()?))
+    }
+
+    /// Add two coordinates, clamping each component to the bounds of the component type.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(
+    ///     axial!(i32::MAX - 1, 0).saturating_add(axial!(5, -5)),
+    ///     axial!(i32::MAX, -5)
+    /// );
+    /// ```
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        axial!(self.q.saturating_add(rhs.q), self.r.saturating_add(rhs.r))
+    }
+
+    /// Subtract two coordinates, clamping each component to the bounds of the component type.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        axial!(self.q.saturating_sub(rhs.q), self.r.saturating_sub(rhs.r))
+    }
+
+    /// Scale a coordinate, clamping each component to the bounds of the component type.
+    pub fn saturating_mul(self, rhs: T) -> Self {
+        axial!(self.q.saturating_mul(rhs), self.r.saturating_mul(rhs))
+    }
+
+    /// Computes the S component, [`None`] if it does not fit the component type.
+    pub fn checked_compute_s(&self) -> Option<T> {
+        self.q.checked_neg()?.checked_sub(self.r)
+    }
+
+    /// Compute distance between two coordinates, [`None`] if it does not fit the component type.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(0, 0).checked_distance(axial!(2, 0)), Some(2));
+    /// assert_eq!(axial!(i32::MIN, 0).checked_distance(axial!(i32::MAX, 0)), None);
+    /// ```
+    pub fn checked_distance(&self, b: Self) -> Option<T> {
+        // The distance is the largest of the three components of the difference.
+        let vec = self.checked_sub(b)?;
+        let s = vec.checked_compute_s()?;
+        Some(
+            vec.q
+                .checked_abs()?
+                .max(vec.r.checked_abs()?)
+                .max(s.checked_abs()?),
+        )
+    }
+
+    /// Rotate a coordinate, [`None`] if a component overflows.
+    ///
+    /// See [`Axial::rotate`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(1, 0).checked_rotate(None, 1), Some(axial!(0, 1)));
+    /// assert_eq!(axial!(i32::MIN + 1, 0).checked_rotate(Some(axial!(1, 0)), 3), None);
+    /// ```
+    pub fn checked_rotate(&self, center: Option<Self>, rot_dir: i32) -> Option<Self> {
+        let center = center.unwrap_or_default();
+        let local = self.checked_sub(center)?;
+        let (q, r, s) = (local.q, local.r, local.checked_compute_s()?);
+
+        // Every 60 degree CW rotation shifts the components right and negates them.
+        let rotated = match rot_dir.rem_euclid(6) {
+            0 => axial!(q, r),
+            1 => axial!(r, s).checked_neg()?,
+            2 => axial!(s, q),
+            3 => axial!(q, r).checked_neg()?,
+            4 => axial!(r, s),
+            5 => axial!(s, q).checked_neg()?,
+            _ => unreachable!(), // should never reach
+        };
+        rotated.checked_add(center)
+    }
+
+    /// Lazily calculate all the coordinates within a range, [`None`] if a coordinate overflows.
+    ///
+    /// See [`Axial::range_iter`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(0, 0).checked_range_iter(1).unwrap().len(), 7);
+    /// assert!(axial!(i32::MAX, 0).checked_range_iter(1).is_none());
+    /// ```
+    pub fn checked_range_iter(&self, range: T) -> Option<AxialRange<T>> {
+        if range > T::ZERO {
+            // Each component is at its extremes on the corners of the range.
+            for dir in 0..6 {
+                let corner = Self::default()
+                    .neighbor(HexDirection::from(dir))
+                    .checked_mul(range)?;
+                self.checked_add(corner)?.checked_compute_s()?;
+            }
+        }
+        Some(self.range_iter(range))
+    }
+
+    /// Calculate all the coordinates within a range, [`None`] if a coordinate overflows.
+    ///
+    /// See [`Axial::range`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn checked_range(&self, range: T) -> Option<Vec<Self>> {
+        Some(self.checked_range_iter(range)?.collect())
+    }
+
+    /// Lazily calculate the coordinates that form a line between two points, [`None`] if the
+    /// length of the line overflows.
+    ///
+    /// See [`Axial::line_iter`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(0, 0).checked_line_iter(axial!(2, 0)).unwrap().len(), 3);
+    /// assert!(axial!(i32::MIN, 0).checked_line_iter(axial!(i32::MAX, 0)).is_none());
+    /// ```
+    pub fn checked_line_iter(&self, b: Self) -> Option<AxialLine<T>> {
+        // The line has one more coordinate than its length.
+        self.checked_distance(b)?.checked_add(T::ONE)?;
+        Some(self.line_iter(b))
+    }
+
+    /// Calculate all the coordinates that form a line between two points, [`None`] if the length
+    /// of the line overflows.
+    ///
+    /// See [`Axial::line`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn checked_line(&self, b: Self) -> Option<Vec<Self>> {
+        Some(self.checked_line_iter(b)?.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_arithmetic() {
+        let a = axial!(3, -4);
+        let b = axial!(-7, 2);
+        assert_eq!(a.checked_add(b), Some(a + b));
+        assert_eq!(a.checked_sub(b), Some(a - b));
+        assert_eq!(a.checked_mul(3), Some(a * 3));
+        assert_eq!(a.checked_div(2), Some(a / 2));
+        assert_eq!(a.checked_neg(), Some(-a));
+
+        assert_eq!(
+            axial!(i32::MAX, 0).checked_add(axial!(0, 1)),
+            Some(axial!(i32::MAX, 1))
+        );
+        assert_eq!(axial!(0, i32::MAX).checked_add(axial!(0, 1)), None);
+        assert_eq!(axial!(i32::MIN, 0).checked_sub(axial!(1, 0)), None);
+        assert_eq!(axial!(1 << 20, 1).checked_mul(1 << 12), None);
+        assert_eq!(axial!(i32::MIN, 0).checked_div(-1), None);
+        assert_eq!(a.checked_div(0), None);
+        assert_eq!(axial!(0, i32::MIN).checked_neg(), None);
+
+        assert_eq!(
+            axial!(100i8, -100).checked_add(axial!(27, -28)),
+            Some(axial!(127, -128))
+        );
+        assert_eq!(axial!(100i8, 0).checked_add(axial!(28, 0)), None);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(
+            axial!(i32::MAX, i32::MIN).saturating_add(axial!(1, -1)),
+            axial!(i32::MAX, i32::MIN)
+        );
+        assert_eq!(
+            axial!(i32::MIN, 3).saturating_sub(axial!(1, 1)),
+            axial!(i32::MIN, 2)
+        );
+        assert_eq!(axial!(100i8, -2).saturating_mul(2), axial!(i8::MAX, -4));
+    }
+
+    #[test]
+    fn checked_distance() {
+        for coord in axial!(3, -1).range_iter(3) {
+            assert_eq!(
+                coord.checked_distance(axial!(-2, 5)),
+                Some(coord.distance(axial!(-2, 5)))
+            );
+        }
+
+        // Fits even though the sum of the components does not.
+        assert_eq!(
+            axial!(0, 0).checked_distance(axial!(i32::MAX, 0)),
+            Some(i32::MAX)
+        );
+        assert_eq!(axial!(0, 0).checked_distance(axial!(i32::MIN, 0)), None);
+        // The s component overflows.
+        let half = i32::MAX / 2 + 1;
+        assert_eq!(axial!(half, half).checked_distance(axial!(0, 0)), None);
+        assert_eq!(
+            axial!(half, -half).checked_distance(axial!(0, 0)),
+            Some(half)
+        );
+    }
+
+    #[test]
+    fn checked_rotate() {
+        let center = axial!(2, -1);
+        for coord in center.range_iter(3) {
+            for rot_dir in -6..=6 {
+                assert_eq!(
+                    coord.checked_rotate(Some(center), rot_dir),
+                    Some(coord.rotate(Some(center), rot_dir))
+                );
+            }
+        }
+
+        assert_eq!(
+            axial!(i32::MAX, 0).checked_rotate(None, 0),
+            Some(axial!(i32::MAX, 0))
+        );
+        assert_eq!(
+            axial!(i32::MAX, 0).checked_rotate(None, 3),
+            Some(axial!(-i32::MAX, 0))
+        );
+        assert_eq!(axial!(i32::MIN, 0).checked_rotate(None, 3), None);
+        assert_eq!(
+            axial!(i32::MAX, 0).checked_rotate(Some(axial!(-1, 0)), 1),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_range() {
+        let center = axial!(4, -2);
+        assert!(center
+            .checked_range_iter(3)
+            .unwrap()
+            .eq(center.range_iter(3)));
+        assert_eq!(center.checked_range_iter(-1).unwrap().len(), 0);
+
+        assert!(axial!(i32::MAX - 2, 0).checked_range_iter(2).is_some());
+        assert!(axial!(i32::MAX - 2, 0).checked_range_iter(3).is_none());
+        assert!(axial!(0, i32::MIN + 2).checked_range_iter(3).is_none());
+        // The s component overflows.
+        assert!(axial!(i32::MIN / 2, i32::MIN / 2)
+            .checked_range_iter(1)
+            .is_none());
+        assert!(axial!(0, 0).checked_range_iter(i32::MAX).is_some());
+        assert!(axial!(1, 0).checked_range_iter(i32::MAX).is_none());
+        assert_eq!(
+            Axial::<i8>::default()
+                .checked_range_iter(i8::MAX)
+                .unwrap()
+                .count(),
+            3 * 127 * 128 + 1
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn checked_line() {
+        assert_eq!(
+            axial!(0, 0).checked_line(axial!(3, -1)),
+            Some(axial!(0, 0).line(axial!(3, -1)))
+        );
+        assert_eq!(axial!(0, 0).checked_line(axial!(i32::MIN, 0)), None);
+        assert!(axial!(1, 0)
+            .checked_line_iter(axial!(i32::MAX, 0))
+            .is_some());
+        assert!(axial!(0, 0)
+            .checked_line_iter(axial!(i32::MAX, 0))
+            .is_none());
+    }
+}
@@ -0,0 +1,435 @@
+//! Cube coordinate system for hex based grids.
+
+use crate::core::coordinate::{Distance, Line, Neighbors, Range};
+use crate::core::parse::{self, ParseCoordError};
+use crate::lib::*;
+
+use super::{axial, Axes, Axial, AxialLine, AxialRange};
+
+/// Cube based coordinates for hexagon grids.
+///
+/// Unlike [`Axial`], all three components are stored and the law `q + r + s = 0` is
+/// enforced on construction. Algorithms that treat the three axes symmetrically, such as
+/// rotations and reflections, are simpler to express in this form.
+///
+/// Conversions to and from [`Axial`] are cheap and lossless.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Axial", into = "Axial"))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct Cube {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+impl From<Axial> for Cube {
+    fn from(value: Axial) -> Self {
+        Cube {
+            q: value.q,
+            r: value.r,
+            s: value.compute_s(),
+        }
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(value: Cube) -> Self {
+        axial!(value.q, value.r)
+    }
+}
+
+impl From<Cube> for (i32, i32, i32) {
+    fn from(value: Cube) -> Self {
+        (value.q, value.r, value.s)
+    }
+}
+
+impl Display for Cube {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cube({}, {}, {})", self.q, self.r, self.s)
+    }
+}
+
+/// Parses the form produced by [`Display`], e.g. `Cube(1, -2, 1)`.
+///
+/// Components not following the law `q + r + s = 0` produce [`ParseCoordError::Invalid`].
+impl FromStr for Cube {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [q, r, s] = parse::components(s, "Cube")?;
+        Cube::new(
+            parse::component(q)?,
+            parse::component(r)?,
+            parse::component(s)?,
+        )
+        .ok_or(ParseCoordError::Invalid)
+    }
+}
+
+impl Cube {
+    /// Constructor for a cube coordinate.
+    ///
+    /// Produces [`None`] if the components do not follow the law `q + r + s = 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::Cube;
+    ///
+    /// assert!(Cube::new(1, 2, -3).is_some());
+    /// assert!(Cube::new(1, 2, 3).is_none());
+    /// ```
+    pub fn new(q: i32, r: i32, s: i32) -> Option<Self> {
+        // Widened so that components summing past the bounds of i32 cannot wrap to 0.
+        if i64::from(q) + i64::from(r) + i64::from(s) == 0 {
+            Some(Cube { q, r, s })
+        } else {
+            None
+        }
+    }
+
+    /// q (x) component
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    /// r (y) component
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+
+    /// s (z) component
+    pub fn s(&self) -> i32 {
+        self.s
+    }
+
+    /// Compute distance between two coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Cube};
+    ///
+    /// let a = Cube::from(axial!(-1, 3));
+    /// let b = Cube::from(axial!(1, 1));
+    /// assert_eq!(a.distance(b), 2);
+    /// ```
+    pub fn distance(&self, b: Self) -> i32 {
+        let vec = *self - b;
+        i32::max(i32::abs(vec.q), i32::max(i32::abs(vec.r), i32::abs(vec.s)))
+    }
+
+    /// Rotate a coordinate.
+    ///
+    /// `center` Optionally can specify a point to rotate about. None will rotate about (0, 0, 0).
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Cube};
+    ///
+    /// let coord = Cube::from(axial!(1, 0)).rotate(None, 1);
+    /// assert_eq!(Axial::from(coord), axial!(0, 1));
+    /// ```
+    pub fn rotate(&self, center: Option<Self>, rot_dir: i32) -> Self {
+        let center = center.unwrap_or_default();
+        let Cube { q, r, s } = *self - center;
+
+        // Every 60 degree CW rotation shifts the components right and negates them.
+        let rotated = match rot_dir.rem_euclid(6) {
+            0 => Cube { q, r, s },
+            1 => Cube {
+                q: -r,
+                r: -s,
+                s: -q,
+            },
+            2 => Cube { q: s, r: q, s: r },
+            3 => Cube {
+                q: -q,
+                r: -r,
+                s: -s,
+            },
+            4 => Cube { q: r, r: s, s: q },
+            5 => Cube {
+                q: -s,
+                r: -q,
+                s: -r,
+            },
+            _ => unreachable!(), // should never reach
+        };
+
+        rotated + center
+    }
+
+    /// Reflect a coordinate across an axis of symmetry.
+    ///
+    /// The component of the provided axis is kept while the other two are swapped.
+    ///
+    /// `center` can be provided to specify a specific point to reflect across. Otherwise, (0, 0, 0) will be used.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Axes, Cube};
+    ///
+    /// let coord = Cube::from(axial!(1, 0)).reflect(None, Axes::S);
+    /// assert_eq!(Axial::from(coord), axial!(0, 1));
+    /// ```
+    pub fn reflect(&self, center: Option<Self>, axes: Axes) -> Self {
+        let center = center.unwrap_or_default();
+        let Cube { q, r, s } = *self - center;
+
+        let reflected = match axes {
+            Axes::Q => Cube { q, r: s, s: r },
+            Axes::R => Cube { q: s, r, s: q },
+            Axes::S => Cube { q: r, r: q, s },
+        };
+
+        reflected + center
+    }
+
+    /// Rounds a floating cube coordinate to an integer coordinate.
+    ///
+    /// Each component is rounded and the component that changed the most is recomputed
+    /// from the other two so the law `q + r + s = 0` is kept.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Cube};
+    ///
+    /// let coord = Cube::round((1.6, 0.7, -2.3));
+    /// assert_eq!(Axial::from(coord), axial!(1, 1));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn round(coord_f: (f64, f64, f64)) -> Self {
+        let (q, r, s) = (coord_f.0.round(), coord_f.1.round(), coord_f.2.round());
+        let (dq, dr, ds) = (
+            (q - coord_f.0).abs(),
+            (r - coord_f.1).abs(),
+            (s - coord_f.2).abs(),
+        );
+
+        Self::fix_round((q as i32, r as i32, s as i32), (dq, dr, ds))
+    }
+
+    /// Rounds a floating cube coordinate to an integer coordinate.
+    ///
+    /// Each component is rounded and the component that changed the most is recomputed
+    /// from the other two so the law `q + r + s = 0` is kept.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Cube};
+    ///
+    /// let coord = Cube::round((1.6, 0.7, -2.3));
+    /// assert_eq!(Axial::from(coord), axial!(1, 1));
+    /// ```
+    #[cfg(not(feature = "std"))]
+    pub fn round(coord_f: (f64, f64, f64)) -> Self {
+        use crate::lib::{fabs, round};
+
+        let (q, r, s) = (round(coord_f.0), round(coord_f.1), round(coord_f.2));
+        let (dq, dr, ds) = (
+            fabs(q - coord_f.0),
+            fabs(r - coord_f.1),
+            fabs(s - coord_f.2),
+        );
+
+        Self::fix_round((q as i32, r as i32, s as i32), (dq, dr, ds))
+    }
+
+    // Recompute the component with the largest rounding error.
+    fn fix_round(rounded: (i32, i32, i32), diff: (f64, f64, f64)) -> Self {
+        let (q, r, s) = rounded;
+        let (dq, dr, ds) = diff;
+
+        if dq > dr && dq > ds {
+            Cube { q: -r - s, r, s }
+        } else if dr > ds {
+            Cube { q, r: -q - s, s }
+        } else {
+            Cube { q, r, s: -q - r }
+        }
+    }
+}
+
+impl Neighbors for Cube {
+    type NeighborIter = array::IntoIter<Self, 6>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        Axial::from(*self).neighbors().map(Cube::from).into_iter()
+    }
+}
+
+impl Distance for Cube {
+    fn distance(&self, b: Self) -> u32 {
+        Cube::distance(self, b).unsigned_abs()
+    }
+}
+
+impl Range for Cube {
+    type RangeIter = iter::Map<AxialRange, fn(Axial) -> Cube>;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        Axial::from(*self).range_iter(range).map(Cube::from)
+    }
+}
+
+impl Line for Cube {
+    type LineIter = iter::Map<AxialLine, fn(Axial) -> Cube>;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        Axial::from(*self).line_iter(b.into()).map(Cube::from)
+    }
+}
+
+impl Add for Cube {
+    type Output = Cube;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Cube {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+            s: self.s + rhs.s,
+        }
+    }
+}
+
+impl Sub for Cube {
+    type Output = Cube;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Cube {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+            s: self.s - rhs.s,
+        }
+    }
+}
+
+impl Mul<i32> for Cube {
+    type Output = Cube;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Cube {
+            q: self.q * rhs,
+            r: self.r * rhs,
+            s: self.s * rhs,
+        }
+    }
+}
+
+impl Neg for Cube {
+    type Output = Cube;
+
+    fn neg(self) -> Self::Output {
+        Cube {
+            q: -self.q,
+            r: -self.r,
+            s: -self.s,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube(q: i32, r: i32) -> Cube {
+        Cube::from(axial!(q, r))
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Cube::new(1, 2, -3), Some(cube(1, 2)));
+        assert_eq!(Cube::new(0, 0, 0), Some(Cube::default()));
+        assert!(Cube::new(1, 1, 1).is_none());
+        assert!(Cube::new(i32::MAX, i32::MAX, 2).is_none());
+        assert_eq!(
+            Cube::new(i32::MAX, i32::MIN, 1),
+            Some(cube(i32::MAX, i32::MIN))
+        );
+    }
+
+    #[test]
+    fn components() {
+        let coord = cube(4, -1);
+        assert_eq!(coord.q(), 4);
+        assert_eq!(coord.r(), -1);
+        assert_eq!(coord.s(), -3);
+        assert_eq!(<(i32, i32, i32)>::from(coord), (4, -1, -3));
+    }
+
+    #[test]
+    fn from_axial() {
+        assert_eq!(Axial::from(Cube::from(axial!(3, -7))), axial!(3, -7));
+        assert_eq!(cube(3, -7).s(), axial!(3, -7).compute_s());
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(cube(4, 2) + cube(1, 3), cube(5, 5));
+        assert_eq!(cube(4, 2) - cube(1, 3), cube(3, -1));
+        assert_eq!(cube(4, 2) * 2, cube(8, 4));
+        assert_eq!(-cube(4, 2), cube(-4, -2));
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(cube(-1, -1).distance(cube(-1, -1)), 0);
+        assert_eq!(cube(-1, -1).distance(cube(1, -1)), 2);
+        assert_eq!(cube(-1, -1).distance(cube(-1, 1)), 2);
+        assert_eq!(cube(-1, -1).distance(cube(2, 1)), 5);
+    }
+
+    #[test]
+    fn rotate() {
+        // CW
+        assert_eq!(cube(-1, 1).rotate(None, 1), cube(-1, 0));
+        assert_eq!(cube(-1, 1).rotate(None, 2), cube(0, -1));
+        assert_eq!(cube(-1, 1).rotate(None, 3), cube(1, -1));
+        assert_eq!(cube(-1, 1).rotate(None, 7), cube(-1, 0));
+
+        // CCW
+        assert_eq!(cube(-1, 1).rotate(None, -1), cube(0, 1));
+        assert_eq!(cube(-1, 1).rotate(None, -2), cube(1, 0));
+        assert_eq!(cube(-1, 1).rotate(None, -3), cube(1, -1));
+
+        // About non (0, 0) center
+        assert_eq!(cube(0, 0).rotate(Some(cube(1, 1)), 1), cube(2, -1));
+        assert_eq!(cube(0, 0).rotate(Some(cube(1, 1)), 2), cube(3, 0));
+        assert_eq!(cube(0, 0).rotate(Some(cube(1, 1)), 3), cube(2, 2));
+    }
+
+    #[test]
+    fn reflect() {
+        assert_eq!(cube(-1, 1).reflect(None, Axes::Q), cube(-1, 0));
+        assert_eq!(cube(1, 3).reflect(Some(cube(1, 2)), Axes::Q), cube(1, 1));
+        assert_eq!(cube(-1, 1).reflect(None, Axes::R), cube(0, 1));
+        assert_eq!(cube(1, 3).reflect(Some(cube(1, 2)), Axes::R), cube(0, 3));
+        assert_eq!(cube(-1, 1).reflect(None, Axes::S), cube(1, -1));
+        assert_eq!(cube(1, 3).reflect(Some(cube(1, 2)), Axes::S), cube(2, 2));
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(Cube::round((1.6, 0.7, -2.3)), cube(1, 1));
+        assert_eq!(Cube::round((0.1, 0.1, -0.2)), cube(0, 0));
+        assert_eq!(Cube::round((-0.4, 0.9, -0.5)), cube(0, 1));
+        assert_eq!(Cube::round((2.0, -3.0, 1.0)), cube(2, -3));
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(format!("{}", cube(1, -2)), "Cube(1, -2, 1)");
+        assert_eq!("Cube(1, -2, 1)".parse(), Ok(cube(1, -2)));
+        assert_eq!(
+            "Cube(2147483647, 2147483647, 2)".parse::<Cube>(),
+            Err(ParseCoordError::Invalid)
+        );
+        assert_eq!(
+            "Cube(1, 2, 1)".parse::<Cube>(),
+            Err(ParseCoordError::Invalid)
+        );
+        assert_eq!("Cube(1, 2)".parse::<Cube>(), Err(ParseCoordError::Format));
+    }
+}
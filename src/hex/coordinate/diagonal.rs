@@ -0,0 +1,228 @@
+//! Diagonal directions on hex based grids.
+
+use crate::lib::*;
+
+use super::{axial, Axial, HexDirection};
+use crate::hex::vertex::{Vertex, VertexDirection};
+
+/// Describes a diagonal direction.
+///
+/// A diagonal leads to a tile at distance 2, passing between two [`HexDirection`] neighbors
+/// through the vertex of the same name, see [`VertexDirection`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum HexDiagonal {
+    /// Diagonal between [`HexDirection::BackLeft`] and [`HexDirection::FrontLeft`]
+    Up,
+    /// Diagonal between [`HexDirection::FrontLeft`] and [`HexDirection::Front`]
+    UpRight,
+    /// Diagonal between [`HexDirection::Front`] and [`HexDirection::FrontRight`]
+    DownRight,
+    /// Diagonal between [`HexDirection::FrontRight`] and [`HexDirection::BackRight`]
+    Down,
+    /// Diagonal between [`HexDirection::BackRight`] and [`HexDirection::Back`]
+    DownLeft,
+    /// Diagonal between [`HexDirection::Back`] and [`HexDirection::BackLeft`]
+    UpLeft,
+}
+
+impl From<i32> for HexDiagonal {
+    fn from(value: i32) -> Self {
+        match value.rem_euclid(6) {
+            0 => HexDiagonal::Up,
+            1 => HexDiagonal::UpRight,
+            2 => HexDiagonal::DownRight,
+            3 => HexDiagonal::Down,
+            4 => HexDiagonal::DownLeft,
+            5 => HexDiagonal::UpLeft,
+            _ => unreachable!(), // should never reach
+        }
+    }
+}
+
+impl From<HexDiagonal> for i32 {
+    fn from(value: HexDiagonal) -> Self {
+        match value {
+            HexDiagonal::Up => 0,
+            HexDiagonal::UpRight => 1,
+            HexDiagonal::DownRight => 2,
+            HexDiagonal::Down => 3,
+            HexDiagonal::DownLeft => 4,
+            HexDiagonal::UpLeft => 5,
+        }
+    }
+}
+
+impl From<HexDiagonal> for VertexDirection {
+    fn from(value: HexDiagonal) -> Self {
+        VertexDirection::from(i32::from(value))
+    }
+}
+
+impl HexDiagonal {
+    /// Converts a HexDiagonal to an [`Axial`] vector.
+    ///
+    /// The vector is the sum of the movement vectors of the two directions it lies between.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDiagonal, axial};
+    ///
+    /// assert_eq!(HexDiagonal::DownRight.to_movement_vector(), axial!(1, 1));
+    /// ```
+    pub fn to_movement_vector(&self) -> Axial {
+        match self {
+            HexDiagonal::Up => axial!(1, -2),
+            HexDiagonal::UpRight => axial!(2, -1),
+            HexDiagonal::DownRight => axial!(1, 1),
+            HexDiagonal::Down => axial!(-1, 2),
+            HexDiagonal::DownLeft => axial!(-2, 1),
+            HexDiagonal::UpLeft => axial!(-1, -1),
+        }
+    }
+
+    /// Get the two directions this diagonal lies between, in CW order.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{HexDiagonal, HexDirection};
+    ///
+    /// assert_eq!(
+    ///     HexDiagonal::DownRight.directions(),
+    ///     [HexDirection::Front, HexDirection::FrontRight]
+    /// );
+    /// ```
+    pub fn directions(&self) -> [HexDirection; 2] {
+        let dir = i32::from(*self);
+        [HexDirection::from(dir - 2), HexDirection::from(dir - 1)]
+    }
+}
+
+impl Axial {
+    /// Get a diagonal neighbor coordinate given a diagonal.
+    ///
+    /// See [`HexDiagonal`] for a reference of directionality.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDiagonal, axial};
+    ///
+    /// assert_eq!(axial!(0, 0).diagonal_neighbor(HexDiagonal::UpLeft), axial!(-1, -1));
+    /// ```
+    pub fn diagonal_neighbor(&self, diagonal: HexDiagonal) -> Self {
+        *self + diagonal.to_movement_vector()
+    }
+
+    /// Get all the diagonal neighbors for this coordinate.
+    ///
+    /// See [`HexDiagonal`] for a reference of directionality.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert!(axial!(0, 0).diagonal_neighbors().contains(&axial!(1, 1)));
+    /// ```
+    pub fn diagonal_neighbors(&self) -> [Self; 6] {
+        [
+            self.diagonal_neighbor(HexDiagonal::Up),
+            self.diagonal_neighbor(HexDiagonal::UpRight),
+            self.diagonal_neighbor(HexDiagonal::DownRight),
+            self.diagonal_neighbor(HexDiagonal::Down),
+            self.diagonal_neighbor(HexDiagonal::DownLeft),
+            self.diagonal_neighbor(HexDiagonal::UpLeft),
+        ]
+    }
+
+    /// Get the vertex a diagonal passes through on its way to the diagonal neighbor.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexDiagonal, axial};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// assert_eq!(
+    ///     axial!(0, 0).diagonal_vertex(HexDiagonal::Up),
+    ///     axial!(0, 0).vertex(VertexDirection::Up)
+    /// );
+    /// ```
+    pub fn diagonal_vertex(&self, diagonal: HexDiagonal) -> Vertex {
+        self.vertex(diagonal.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_i32() {
+        for dir in 0..6 {
+            assert_eq!(i32::from(HexDiagonal::from(dir)), dir);
+        }
+        assert_eq!(HexDiagonal::from(-1), HexDiagonal::UpLeft);
+        assert_eq!(HexDiagonal::from(8), HexDiagonal::DownRight);
+    }
+
+    #[test]
+    fn to_vertex_direction() {
+        assert_eq!(VertexDirection::from(HexDiagonal::Up), VertexDirection::Up);
+        assert_eq!(
+            VertexDirection::from(HexDiagonal::DownLeft),
+            VertexDirection::DownLeft
+        );
+    }
+
+    #[test]
+    fn to_movement_vector() {
+        for dir in 0..6 {
+            let diagonal = HexDiagonal::from(dir);
+            let [a, b] = diagonal.directions();
+            assert_eq!(
+                diagonal.to_movement_vector(),
+                a.to_movement_vector() + b.to_movement_vector()
+            );
+            assert_eq!(diagonal.to_movement_vector().distance(axial!(0, 0)), 2);
+        }
+    }
+
+    #[test]
+    fn diagonal_neighbors() {
+        let coord = axial!(2, -1);
+        let diagonals = coord.diagonal_neighbors();
+        for (dir, diagonal) in diagonals.iter().enumerate() {
+            assert_eq!(
+                *diagonal,
+                coord.diagonal_neighbor(HexDiagonal::from(dir as i32))
+            );
+            assert_eq!(diagonal.distance(coord), 2);
+
+            // Diagonals are exactly the tiles adjacent to two of the neighbors.
+            let shared = coord
+                .neighbors()
+                .iter()
+                .filter(|neighbor| neighbor.neighbors().contains(diagonal))
+                .count();
+            assert_eq!(shared, 2);
+        }
+    }
+
+    #[test]
+    fn diagonal_vertex() {
+        for dir in 0..6 {
+            let coord = axial!(-1, 3);
+            let diagonal = HexDiagonal::from(dir);
+            let vertex = coord.diagonal_vertex(diagonal);
+
+            // The vertex is shared by self and the two neighbors the diagonal passes between.
+            assert!(coord.vertices().contains(&vertex));
+            for direction in diagonal.directions() {
+                assert!(coord.neighbor(direction).vertices().contains(&vertex));
+            }
+            assert!(!coord
+                .diagonal_neighbor(diagonal)
+                .vertices()
+                .contains(&vertex));
+        }
+    }
+}
@@ -0,0 +1,548 @@
+//! Doubled coordinate systems for hex based grids.
+
+use crate::core::coordinate::{Distance, Line, Neighbors, Range};
+use crate::core::parse::{self, ParseCoordError};
+use crate::lib::*;
+
+use super::{axial, Axial, AxialLine, AxialRange, HexDirection};
+
+/// Describes which axis of a [`Doubled`] coordinate is doubled.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum DoubledLayout {
+    /// Columns step by two, used with pointy top hexagons.
+    #[default]
+    Width,
+    /// Rows step by two, used with flat top hexagons.
+    Height,
+}
+
+impl Display for DoubledLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DoubledLayout::Width => "Width",
+            DoubledLayout::Height => "Height",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for DoubledLayout {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "Width" => Ok(DoubledLayout::Width),
+            "Height" => Ok(DoubledLayout::Height),
+            _ => Err(ParseCoordError::Component),
+        }
+    }
+}
+
+/// Doubled coordinates for hexagon grids.
+///
+/// Doubled coordinates index hexagons by column and row where one of the two axes steps by two
+/// between neighbors, see [`DoubledLayout`]. This removes the parity branches of [`super::Offset`]
+/// coordinates at the cost of half the indices being unused.
+///
+/// A doubled coordinate is only valid if `col + row` is even. Parsing and deserializing reject
+/// invalid coordinates with [`ParseCoordError::Invalid`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DoubledParts"))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct Doubled {
+    /// Column of the coordinate
+    pub col: i32,
+    /// Row of the coordinate
+    pub row: i32,
+    /// Which axis is doubled
+    pub layout: DoubledLayout,
+}
+
+// Unchecked form of a doubled coordinate, deserialized then validated like parsing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct DoubledParts {
+    col: i32,
+    row: i32,
+    layout: DoubledLayout,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DoubledParts> for Doubled {
+    type Error = ParseCoordError;
+
+    fn try_from(value: DoubledParts) -> Result<Self, Self::Error> {
+        let coord = doubled!(value.col, value.row, value.layout);
+        match coord.is_valid() {
+            true => Ok(coord),
+            false => Err(ParseCoordError::Invalid),
+        }
+    }
+}
+
+/// Iterator over the coordinates within a range, see [`Range`] for [`Doubled`].
+pub type DoubledRange = iter::Map<
+    iter::Zip<iter::Flatten<option::IntoIter<AxialRange>>, iter::Repeat<DoubledLayout>>,
+    fn((Axial, DoubledLayout)) -> Doubled,
+>;
+
+/// Iterator over the coordinates forming a line, see [`Line`] for [`Doubled`].
+pub type DoubledLine = iter::Map<
+    iter::Zip<iter::Flatten<option::IntoIter<AxialLine>>, iter::Repeat<DoubledLayout>>,
+    fn((Axial, DoubledLayout)) -> Doubled,
+>;
+
+/// Helper macro to create [`Doubled`] structs.
+#[macro_export]
+macro_rules! doubled {
+    ($col:expr, $row:expr, $layout:expr) => {
+        Doubled {
+            col: $col,
+            row: $row,
+            layout: $layout,
+        }
+    };
+}
+pub use doubled;
+
+/// Coordinates that are not [valid](Doubled::is_valid) produce [`ParseCoordError::Invalid`].
+impl TryFrom<Doubled> for Axial {
+    type Error = ParseCoordError;
+
+    fn try_from(value: Doubled) -> Result<Self, Self::Error> {
+        value.to_axial().ok_or(ParseCoordError::Invalid)
+    }
+}
+
+impl Display for Doubled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Doubled({}, {}, {})", self.col, self.row, self.layout)
+    }
+}
+
+/// Parses the form produced by [`Display`], e.g. `Doubled(2, 0, Width)`.
+///
+/// Coordinates that are not [valid](Doubled::is_valid) produce [`ParseCoordError::Invalid`].
+impl FromStr for Doubled {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [col, row, layout] = parse::components(s, "Doubled")?;
+        let coord = doubled!(
+            parse::component(col)?,
+            parse::component(row)?,
+            parse::component(layout)?
+        );
+        match coord.is_valid() {
+            true => Ok(coord),
+            false => Err(ParseCoordError::Invalid),
+        }
+    }
+}
+
+impl Doubled {
+    /// Convert an [`Axial`] coordinate into a doubled coordinate of the given layout.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Doubled, doubled, DoubledLayout};
+    ///
+    /// let coord = Doubled::from_axial(axial!(1, 2), DoubledLayout::Width);
+    /// assert_eq!(coord, doubled!(4, 2, DoubledLayout::Width));
+    /// ```
+    pub fn from_axial(coord: Axial, layout: DoubledLayout) -> Self {
+        match layout {
+            DoubledLayout::Width => doubled!(2 * coord.q + coord.r, coord.r, layout),
+            DoubledLayout::Height => doubled!(coord.q, 2 * coord.r + coord.q, layout),
+        }
+    }
+
+    /// Convert to an [`Axial`] coordinate.
+    ///
+    /// Produces [`None`] if the coordinate is not valid according to [`Doubled::is_valid`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Doubled, doubled, DoubledLayout};
+    ///
+    /// assert_eq!(doubled!(4, 2, DoubledLayout::Width).to_axial(), Some(axial!(1, 2)));
+    /// assert!(doubled!(3, 2, DoubledLayout::Width).to_axial().is_none());
+    /// ```
+    pub fn to_axial(&self) -> Option<Axial> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        match self.layout {
+            DoubledLayout::Width => Some(axial!((self.col - self.row) / 2, self.row)),
+            DoubledLayout::Height => Some(axial!(self.col, (self.row - self.col) / 2)),
+        }
+    }
+
+    /// Determines if the coordinate maps to a hexagon.
+    ///
+    /// Half of the doubled indices are unused, a coordinate is valid when `col + row` is even.
+    pub fn is_valid(&self) -> bool {
+        (self.col ^ self.row) & 1 == 0
+    }
+
+    /// Get a neighbor coordinate given a direction.
+    ///
+    /// See [`HexDirection`] for a reference of directionality.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{HexDirection, Doubled, doubled, DoubledLayout};
+    ///
+    /// let coord = doubled!(0, 0, DoubledLayout::Width).neighbor(HexDirection::Front);
+    /// assert_eq!(coord, doubled!(2, 0, DoubledLayout::Width));
+    /// ```
+    pub fn neighbor(&self, direction: HexDirection) -> Self {
+        let (dc, dr) = match (self.layout, direction) {
+            (DoubledLayout::Width, HexDirection::Front) => (2, 0),
+            (DoubledLayout::Width, HexDirection::FrontRight) => (1, 1),
+            (DoubledLayout::Width, HexDirection::BackRight) => (-1, 1),
+            (DoubledLayout::Width, HexDirection::Back) => (-2, 0),
+            (DoubledLayout::Width, HexDirection::BackLeft) => (-1, -1),
+            (DoubledLayout::Width, HexDirection::FrontLeft) => (1, -1),
+            (DoubledLayout::Height, HexDirection::Front) => (1, 1),
+            (DoubledLayout::Height, HexDirection::FrontRight) => (0, 2),
+            (DoubledLayout::Height, HexDirection::BackRight) => (-1, 1),
+            (DoubledLayout::Height, HexDirection::Back) => (-1, -1),
+            (DoubledLayout::Height, HexDirection::BackLeft) => (0, -2),
+            (DoubledLayout::Height, HexDirection::FrontLeft) => (1, -1),
+        };
+
+        doubled!(self.col + dc, self.row + dr, self.layout)
+    }
+
+    /// Get all the neighbors for this coordinate.
+    ///
+    /// The neighbors are in the order of [`HexDirection`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Doubled, doubled, DoubledLayout};
+    ///
+    /// let neighbors = doubled!(1, 3, DoubledLayout::Height).neighbors();
+    /// ```
+    pub fn neighbors(&self) -> [Self; 6] {
+        [
+            self.neighbor(HexDirection::Front),
+            self.neighbor(HexDirection::FrontRight),
+            self.neighbor(HexDirection::BackRight),
+            self.neighbor(HexDirection::Back),
+            self.neighbor(HexDirection::BackLeft),
+            self.neighbor(HexDirection::FrontLeft),
+        ]
+    }
+
+    /// Compute distance between two coordinates.
+    ///
+    /// The coordinates do not need to share a layout. Produces [`None`] if either coordinate is
+    /// not valid according to [`Doubled::is_valid`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Doubled, doubled, DoubledLayout};
+    ///
+    /// let dist = doubled!(0, 0, DoubledLayout::Width).distance(doubled!(4, 2, DoubledLayout::Width));
+    /// assert_eq!(dist, Some(3));
+    /// ```
+    pub fn distance(&self, b: Self) -> Option<i32> {
+        Some(self.to_axial()?.distance(b.to_axial()?))
+    }
+
+    /// Enumerate every valid coordinate inside a rectangle.
+    ///
+    /// `min` and `max` are inclusive (col, row) corners. Coordinates are produced row by row,
+    /// by ascending column.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Doubled, doubled, DoubledLayout};
+    ///
+    /// let map = Doubled::rect((0, 0), (5, 2), DoubledLayout::Width).collect::<Vec<_>>();
+    /// assert_eq!(map.len(), 9);
+    /// assert_eq!(map[3], doubled!(1, 1, DoubledLayout::Width));
+    /// ```
+    pub fn rect(
+        min: (i32, i32),
+        max: (i32, i32),
+        layout: DoubledLayout,
+    ) -> impl Iterator<Item = Self> {
+        (min.1..=max.1).flat_map(move |row| {
+            // Skip the column of the wrong parity for this row.
+            let start = min.0 + ((min.0 + row) & 1);
+            (start..=max.0)
+                .step_by(2)
+                .map(move |col| doubled!(col, row, layout))
+        })
+    }
+}
+
+/// Coordinates that are not [valid](Doubled::is_valid) have no neighbors.
+impl Neighbors for Doubled {
+    type NeighborIter = iter::Flatten<option::IntoIter<[Self; 6]>>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        self.is_valid()
+            .then(|| Doubled::neighbors(self))
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// Coordinates that are not [valid](Doubled::is_valid) cannot be reached, their distance is
+/// [`u32::MAX`].
+impl Distance for Doubled {
+    fn distance(&self, b: Self) -> u32 {
+        Doubled::distance(self, b).map_or(u32::MAX, i32::unsigned_abs)
+    }
+}
+
+/// The coordinates are in the layout of self, none if self is not [valid](Doubled::is_valid).
+impl Range for Doubled {
+    type RangeIter = DoubledRange;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        let to_doubled: fn((Axial, DoubledLayout)) -> Doubled =
+            |(coord, layout)| Doubled::from_axial(coord, layout);
+        self.to_axial()
+            .map(|coord| coord.range_iter(range))
+            .into_iter()
+            .flatten()
+            .zip(iter::repeat(self.layout))
+            .map(to_doubled)
+    }
+}
+
+/// The coordinates are in the layout of self, `b` does not need to share it. None if either
+/// coordinate is not [valid](Doubled::is_valid).
+impl Line for Doubled {
+    type LineIter = DoubledLine;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        let to_doubled: fn((Axial, DoubledLayout)) -> Doubled =
+            |(coord, layout)| Doubled::from_axial(coord, layout);
+        self.to_axial()
+            .zip(b.to_axial())
+            .map(|(a, b)| a.line_iter(b))
+            .into_iter()
+            .flatten()
+            .zip(iter::repeat(self.layout))
+            .map(to_doubled)
+    }
+}
+
+impl Axial {
+    /// Convert to a [`Doubled`] coordinate of the given layout.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Doubled, doubled, DoubledLayout};
+    ///
+    /// assert_eq!(axial!(1, 2).to_doubled(DoubledLayout::Height), doubled!(1, 5, DoubledLayout::Height));
+    /// ```
+    pub fn to_doubled(&self, layout: DoubledLayout) -> Doubled {
+        Doubled::from_axial(*self, layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [DoubledLayout; 2] = [DoubledLayout::Width, DoubledLayout::Height];
+
+    #[test]
+    fn doubled_macro() {
+        assert_eq!(
+            Doubled {
+                col: 1,
+                row: 3,
+                layout: DoubledLayout::Height
+            },
+            doubled!(1, 3, DoubledLayout::Height)
+        );
+    }
+
+    #[test]
+    fn from_axial() {
+        assert_eq!(
+            Doubled::from_axial(axial!(1, 2), DoubledLayout::Width),
+            doubled!(4, 2, DoubledLayout::Width)
+        );
+        assert_eq!(
+            Doubled::from_axial(axial!(-3, 1), DoubledLayout::Width),
+            doubled!(-5, 1, DoubledLayout::Width)
+        );
+        assert_eq!(
+            Doubled::from_axial(axial!(1, 2), DoubledLayout::Height),
+            doubled!(1, 5, DoubledLayout::Height)
+        );
+        assert_eq!(
+            Doubled::from_axial(axial!(-3, 1), DoubledLayout::Height),
+            doubled!(-3, -1, DoubledLayout::Height)
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn to_axial() {
+        for layout in LAYOUTS {
+            for coord in axial!(0, 0).range(6) {
+                let dbl = coord.to_doubled(layout);
+                assert!(dbl.is_valid());
+                assert_eq!(dbl.to_axial(), Some(coord));
+                assert_eq!(Axial::try_from(dbl), Ok(coord));
+            }
+        }
+
+        assert!(doubled!(1, 0, DoubledLayout::Width).to_axial().is_none());
+        assert_eq!(
+            Axial::try_from(doubled!(0, -1, DoubledLayout::Height)),
+            Err(ParseCoordError::Invalid)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        for layout in LAYOUTS {
+            let coord = doubled!(-3, 5, layout);
+            let json = serde_json::to_string(&coord).unwrap();
+            assert_eq!(serde_json::from_str::<Doubled>(&json).unwrap(), coord);
+
+            let json = serde_json::to_string(&layout).unwrap();
+            assert_eq!(
+                serde_json::from_str::<DoubledLayout>(&json).unwrap(),
+                layout
+            );
+        }
+
+        // Invalid coordinates are rejected like when parsing.
+        assert!(serde_json::from_str::<Doubled>(r#"{"col":1,"row":0,"layout":"Width"}"#).is_err());
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(doubled!(0, 0, DoubledLayout::Width).is_valid());
+        assert!(doubled!(-1, 3, DoubledLayout::Height).is_valid());
+        assert!(!doubled!(-1, 2, DoubledLayout::Width).is_valid());
+        assert!(!doubled!(0, 1, DoubledLayout::Height).is_valid());
+        assert!(doubled!(i32::MAX, i32::MAX, DoubledLayout::Width).is_valid());
+        assert!(!doubled!(i32::MAX, i32::MIN, DoubledLayout::Height).is_valid());
+    }
+
+    #[test]
+    fn neighbors() {
+        assert_eq!(
+            doubled!(0, 0, DoubledLayout::Width).neighbors(),
+            [
+                doubled!(2, 0, DoubledLayout::Width),
+                doubled!(1, 1, DoubledLayout::Width),
+                doubled!(-1, 1, DoubledLayout::Width),
+                doubled!(-2, 0, DoubledLayout::Width),
+                doubled!(-1, -1, DoubledLayout::Width),
+                doubled!(1, -1, DoubledLayout::Width),
+            ]
+        );
+        assert_eq!(
+            doubled!(0, 0, DoubledLayout::Height).neighbors(),
+            [
+                doubled!(1, 1, DoubledLayout::Height),
+                doubled!(0, 2, DoubledLayout::Height),
+                doubled!(-1, 1, DoubledLayout::Height),
+                doubled!(-1, -1, DoubledLayout::Height),
+                doubled!(0, -2, DoubledLayout::Height),
+                doubled!(1, -1, DoubledLayout::Height),
+            ]
+        );
+
+        // Neighbors agree with axial.
+        for layout in LAYOUTS {
+            for coord in [axial!(0, 0), axial!(3, -2), axial!(-4, 1)] {
+                assert_eq!(
+                    coord.to_doubled(layout).neighbors(),
+                    coord.neighbors().map(|n| n.to_doubled(layout))
+                );
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn distance() {
+        assert_eq!(
+            doubled!(0, 0, DoubledLayout::Width).distance(doubled!(4, 2, DoubledLayout::Width)),
+            Some(3)
+        );
+        assert_eq!(
+            doubled!(0, 0, DoubledLayout::Height).distance(doubled!(1, 5, DoubledLayout::Height)),
+            Some(3)
+        );
+        assert_eq!(
+            doubled!(0, 0, DoubledLayout::Width).distance(doubled!(1, 0, DoubledLayout::Width)),
+            None
+        );
+
+        // Distance agrees with axial, in and across layouts.
+        let b = axial!(2, -1);
+        for layout in LAYOUTS {
+            for coord in axial!(0, 0).range(5) {
+                for b_layout in LAYOUTS {
+                    assert_eq!(
+                        coord.to_doubled(layout).distance(b.to_doubled(b_layout)),
+                        Some(coord.distance(b))
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn rect() {
+        assert_eq!(
+            Doubled::rect((0, 0), (3, 1), DoubledLayout::Width).collect::<Vec<_>>(),
+            vec![
+                doubled!(0, 0, DoubledLayout::Width),
+                doubled!(2, 0, DoubledLayout::Width),
+                doubled!(1, 1, DoubledLayout::Width),
+                doubled!(3, 1, DoubledLayout::Width),
+            ]
+        );
+        assert_eq!(
+            Doubled::rect((-1, -1), (0, 1), DoubledLayout::Height).collect::<Vec<_>>(),
+            vec![
+                doubled!(-1, -1, DoubledLayout::Height),
+                doubled!(0, 0, DoubledLayout::Height),
+                doubled!(-1, 1, DoubledLayout::Height),
+            ]
+        );
+        assert_eq!(
+            Doubled::rect((2, 2), (1, 1), DoubledLayout::Width).count(),
+            0
+        );
+        assert!(Doubled::rect((-7, -3), (8, 6), DoubledLayout::Height).all(|c| c.is_valid()));
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!("{}", doubled!(2, 0, DoubledLayout::Width)),
+            "Doubled(2, 0, Width)"
+        );
+        for layout in LAYOUTS {
+            let coord = doubled!(3, 5, layout);
+            assert_eq!(format!("{layout}").parse(), Ok(layout));
+            assert_eq!(format!("{coord}").parse(), Ok(coord));
+        }
+        assert_eq!(
+            "Doubled(1, 0, Height)".parse::<Doubled>(),
+            Err(ParseCoordError::Invalid)
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Fractional coordinates for hex based grids.
+
+use crate::lib::*;
+
+use super::Axial;
+
+/// Fractional axial coordinates for hexagon grids.
+///
+/// Represents a position anywhere in hex space, including positions between tile centers.
+/// Integer components land on the center of the [`Axial`] tile with the same components.
+///
+/// This coordinate system follows the law that `q + r + s = 0`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialOrd, PartialEq, Debug, Default)]
+pub struct FracAxial {
+    /// q (x) coordinate
+    pub q: f64,
+    /// r (y) coordinate
+    pub r: f64,
+}
+
+impl From<Axial> for FracAxial {
+    fn from(value: Axial) -> Self {
+        FracAxial::new(value.q as f64, value.r as f64)
+    }
+}
+
+impl From<FracAxial> for (f64, f64) {
+    fn from(value: FracAxial) -> Self {
+        (value.q, value.r)
+    }
+}
+
+impl FracAxial {
+    /// Constructor for a fractional coordinate.
+    pub const fn new(q: f64, r: f64) -> Self {
+        Self { q, r }
+    }
+
+    /// Computes the S component.
+    ///
+    /// Follows the law of `q + r + s = 0`
+    pub fn compute_s(&self) -> f64 {
+        -self.q - self.r
+    }
+
+    /// Compute distance between two coordinates.
+    ///
+    /// The distance is measured in tiles along the grid, so a straight step to a neighbor center is 1.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::FracAxial;
+    ///
+    /// let dist = FracAxial::new(0.0, 0.0).distance(FracAxial::new(1.5, 0.0));
+    /// assert_eq!(dist, 1.5);
+    /// ```
+    pub fn distance(&self, b: Self) -> f64 {
+        let vec = *self - b;
+        (f64::abs(vec.q) + f64::abs(vec.q + vec.r) + f64::abs(vec.r)) / 2.0
+    }
+
+    /// Performs linear interpolation between two coordinates.
+    ///
+    /// Given time `t`, or a percentage, calculate an in between value along the line.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::FracAxial;
+    ///
+    /// let coord = FracAxial::new(0.0, 0.0).lerp(FracAxial::new(3.0, -1.0), 0.5);
+    /// assert_eq!(coord, FracAxial::new(1.5, -0.5));
+    /// ```
+    pub fn lerp(&self, b: Self, t: f64) -> Self {
+        FracAxial::new(
+            crate::core::misc::lerp(self.q, b.q, t),
+            crate::core::misc::lerp(self.r, b.r, t),
+        )
+    }
+
+    /// Rounds to the [`Axial`] tile that contains this position.
+    ///
+    /// See [`Axial::round`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, FracAxial};
+    ///
+    /// assert_eq!(FracAxial::new(1.6, 3.2).round(), axial!(2, 3));
+    /// ```
+    pub fn round(&self) -> Axial {
+        Axial::round((self.q, self.r))
+    }
+}
+
+impl Add for FracAxial {
+    type Output = FracAxial;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        FracAxial::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for FracAxial {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for FracAxial {
+    type Output = FracAxial;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        FracAxial::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for FracAxial {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f64> for FracAxial {
+    type Output = FracAxial;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        FracAxial::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Div<f64> for FracAxial {
+    type Output = FracAxial;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        FracAxial::new(self.q / rhs, self.r / rhs)
+    }
+}
+
+impl Neg for FracAxial {
+    type Output = FracAxial;
+
+    fn neg(self) -> Self::Output {
+        FracAxial::new(-self.q, -self.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::*;
+
+    use super::*;
+    use crate::axial;
+
+    #[test]
+    fn from_axial() {
+        assert_eq!(FracAxial::from(axial!(3, -2)), FracAxial::new(3.0, -2.0));
+        assert_eq!(<(f64, f64)>::from(FracAxial::new(0.5, 1.5)), (0.5, 1.5));
+    }
+
+    #[test]
+    fn compute_s() {
+        assert_f64_near!(FracAxial::new(0.5, 1.25).compute_s(), -1.75);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut coord = FracAxial::new(1.5, -0.5) + FracAxial::new(0.25, 1.0);
+        assert_eq!(coord, FracAxial::new(1.75, 0.5));
+
+        coord -= FracAxial::new(0.75, 0.5);
+        assert_eq!(coord, FracAxial::new(1.0, 0.0));
+
+        coord += FracAxial::new(1.0, 1.0);
+        assert_eq!(coord, FracAxial::new(2.0, 1.0));
+
+        assert_eq!(coord - FracAxial::new(2.0, 2.0), FracAxial::new(0.0, -1.0));
+        assert_eq!(coord * 0.5, FracAxial::new(1.0, 0.5));
+        assert_eq!(coord / 4.0, FracAxial::new(0.5, 0.25));
+        assert_eq!(-coord, FracAxial::new(-2.0, -1.0));
+    }
+
+    #[test]
+    fn distance() {
+        assert_f64_near!(
+            FracAxial::new(0.0, 0.0).distance(FracAxial::new(1.5, 0.0)),
+            1.5
+        );
+        assert_f64_near!(
+            FracAxial::new(-1.0, -1.0).distance(FracAxial::new(2.0, 1.0)),
+            5.0
+        );
+        assert_f64_near!(
+            FracAxial::new(0.5, 0.5).distance(FracAxial::new(0.0, 0.0)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let a = FracAxial::new(-1.0, -1.0);
+        let b = FracAxial::new(9.0, 19.0);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.25), FracAxial::new(1.5, 4.0));
+        assert_eq!(a.lerp(b, -0.25), FracAxial::new(-3.5, -6.0));
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(FracAxial::new(2.5, 1.5).round(), axial!(2, 2));
+        assert_eq!(FracAxial::new(-2.5, 1.5).round(), axial!(-3, 2));
+        assert_eq!(FracAxial::new(0.1, -0.2).round(), axial!(0, 0));
+
+        // Lerp then round is equivalent to the axial lerp.
+        let (a, b) = (axial!(-1, -1), axial!(9, 19));
+        for t in [0.0, 0.1, 0.33, 0.5, 0.75, 1.0] {
+            assert_eq!(
+                FracAxial::from(a).lerp(FracAxial::from(b), t).round(),
+                a.lerp(b, t)
+            );
+        }
+    }
+}
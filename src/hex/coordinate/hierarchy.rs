@@ -0,0 +1,363 @@
+//! Aperture-7 hierarchy of hex cells at multiple resolutions.
+
+use crate::core::parse::{self, ParseCoordError};
+use crate::lib::*;
+
+use super::{axial, Axial, SuperhexLayout};
+
+// Seven cells, a center and its neighbors, make up the cell at the next resolution.
+const APERTURE: SuperhexLayout = SuperhexLayout::new(1);
+
+/// A hex cell within an aperture-7 hierarchy.
+///
+/// Resolution 0 cells are the hexes of the regular [`Axial`] grid. Every increase in resolution
+/// groups seven cells, a center cell and its six neighbors, into one larger cell. Since hex grids
+/// are unbounded, resolutions count upwards from the base grid, the larger the resolution the
+/// coarser the cell.
+///
+/// The coordinate of a cell addresses it among the cells of the same resolution, which form an
+/// axial grid of their own. Neighbor and distance queries on the coordinate apply to cells of the
+/// same resolution.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct HexCell {
+    /// Resolution of the cell, 0 being the base grid.
+    pub resolution: u32,
+    /// Coordinate of the cell among cells of the same resolution.
+    pub coord: Axial,
+}
+
+/// Cells are ordered by resolution, then by q and r.
+impl Ord for HexCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.resolution, self.coord.q, self.coord.r).cmp(&(
+            other.resolution,
+            other.coord.q,
+            other.coord.r,
+        ))
+    }
+}
+
+impl PartialOrd for HexCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Axial> for HexCell {
+    fn from(value: Axial) -> Self {
+        HexCell::new(value, 0)
+    }
+}
+
+impl Display for HexCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HexCell({}, {}, {})",
+            self.resolution, self.coord.q, self.coord.r
+        )
+    }
+}
+
+/// Parses the form produced by [`Display`], the resolution followed by the coordinate, e.g.
+/// `HexCell(2, 1, 0)`.
+impl FromStr for HexCell {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [resolution, q, r] = parse::components(s, "HexCell")?;
+        Ok(HexCell::new(
+            axial!(parse::component(q)?, parse::component(r)?),
+            parse::component(resolution)?,
+        ))
+    }
+}
+
+impl HexCell {
+    /// Constructor for a cell.
+    pub const fn new(coord: Axial, resolution: u32) -> Self {
+        Self { resolution, coord }
+    }
+
+    /// Get the cell one resolution up that contains this cell.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexCell, axial};
+    ///
+    /// assert_eq!(HexCell::from(axial!(1, 0)).parent(), HexCell::new(axial!(0, 0), 1));
+    /// ```
+    pub fn parent(&self) -> Self {
+        HexCell::new(APERTURE.chunk(self.coord), self.resolution + 1)
+    }
+
+    /// Get the seven cells one resolution down that make up this cell.
+    ///
+    /// The center child is first followed by its neighbors. Returns [`None`] at resolution 0.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexCell, axial};
+    ///
+    /// let children = HexCell::new(axial!(0, 0), 1).children().unwrap();
+    /// assert_eq!(children[0], HexCell::from(axial!(0, 0)));
+    /// ```
+    pub fn children(&self) -> Option<[Self; 7]> {
+        let resolution = self.resolution.checked_sub(1)?;
+        let center = APERTURE.center(self.coord);
+
+        let mut children = [HexCell::new(center, resolution); 7];
+        for (child, coord) in children[1..].iter_mut().zip(center.neighbors()) {
+            child.coord = coord;
+        }
+        Some(children)
+    }
+
+    /// Convert the cell to another resolution.
+    ///
+    /// A coarser resolution produces the ancestor containing this cell. A finer resolution
+    /// produces the descendant at the center of this cell.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexCell, axial};
+    ///
+    /// let cell = HexCell::from(axial!(6, -2));
+    /// assert_eq!(cell.to_resolution(2), HexCell::new(axial!(1, 0), 2));
+    /// assert_eq!(cell.to_resolution(2).to_resolution(0), HexCell::from(axial!(8, -5)));
+    /// ```
+    pub fn to_resolution(&self, resolution: u32) -> Self {
+        let mut cell = *self;
+        while cell.resolution < resolution {
+            cell = cell.parent();
+        }
+        while cell.resolution > resolution {
+            cell = HexCell::new(APERTURE.center(cell.coord), cell.resolution - 1);
+        }
+        cell
+    }
+
+    /// Determines if another cell is this cell or one of its descendants.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexCell, axial};
+    ///
+    /// let cell = HexCell::new(axial!(0, 0), 1);
+    /// assert!(cell.contains(HexCell::from(axial!(1, -1))));
+    /// assert!(!cell.contains(HexCell::from(axial!(2, 0))));
+    /// ```
+    pub fn contains(&self, other: Self) -> bool {
+        other.resolution <= self.resolution && other.to_resolution(self.resolution) == *self
+    }
+
+    /// Compact a set of cells, replacing every complete group of seven children by their parent.
+    ///
+    /// Duplicate cells and cells contained by another cell of the set are removed. The result is
+    /// sorted by resolution then coordinate.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexCell, axial};
+    ///
+    /// let children = HexCell::new(axial!(0, 0), 1).children().unwrap();
+    /// assert_eq!(HexCell::compact(&children), vec![HexCell::new(axial!(0, 0), 1)]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn compact(cells: &[Self]) -> Vec<Self> {
+        let mut set = cells.iter().copied().collect::<BTreeSet<_>>();
+        let Some(max_resolution) = set.last().map(|cell| cell.resolution) else {
+            return Vec::new();
+        };
+
+        // Drop cells already covered by a coarser cell.
+        let covered = set
+            .iter()
+            .filter(|cell| {
+                (cell.resolution + 1..=max_resolution)
+                    .any(|resolution| set.contains(&cell.to_resolution(resolution)))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        for cell in covered {
+            set.remove(&cell);
+        }
+
+        // Promote complete groups, a promoted parent may complete a group at the next resolution.
+        let mut resolution = set.first().map_or(0, |cell| cell.resolution);
+        while let Some(level) = set
+            .iter()
+            .map(|cell| cell.resolution)
+            .find(|level| *level >= resolution)
+        {
+            resolution = level;
+
+            let parents = set
+                .iter()
+                .filter(|cell| cell.resolution == resolution)
+                .map(|cell| cell.parent())
+                .collect::<BTreeSet<_>>();
+
+            for parent in parents {
+                let children = parent.children().unwrap_or_default();
+                if children.iter().all(|child| set.contains(child)) {
+                    for child in children {
+                        set.remove(&child);
+                    }
+                    set.insert(parent);
+                }
+            }
+
+            resolution += 1;
+        }
+
+        set.into_iter().collect()
+    }
+
+    /// Expand a set of cells into their descendants at a resolution.
+    ///
+    /// Duplicates are removed and the result is sorted by coordinate. Returns [`None`] if a cell is
+    /// finer than the resolution.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, HexCell, axial};
+    ///
+    /// let cells = HexCell::uncompact(&[HexCell::new(axial!(0, 0), 2)], 0).unwrap();
+    /// assert_eq!(cells.len(), 49);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn uncompact(cells: &[Self], resolution: u32) -> Option<Vec<Self>> {
+        let mut set = BTreeSet::new();
+        let mut stack = Vec::new();
+
+        for cell in cells {
+            if cell.resolution < resolution {
+                return None;
+            }
+            stack.push(*cell);
+
+            while let Some(cell) = stack.pop() {
+                match cell.resolution == resolution {
+                    true => {
+                        set.insert(cell);
+                    }
+                    false => stack.extend(cell.children().unwrap_or_default()),
+                }
+            }
+        }
+
+        Some(set.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axial;
+
+    #[test]
+    fn parent() {
+        assert_eq!(
+            HexCell::from(axial!(0, 0)).parent(),
+            HexCell::new(axial!(0, 0), 1)
+        );
+        assert_eq!(
+            HexCell::from(axial!(2, 0)).parent(),
+            HexCell::new(axial!(1, 0), 1)
+        );
+        assert_eq!(
+            HexCell::new(axial!(2, 0), 3).parent(),
+            HexCell::new(axial!(1, 0), 4)
+        );
+    }
+
+    #[test]
+    fn children() {
+        assert_eq!(HexCell::from(axial!(1, 1)).children(), None);
+
+        for cell in axial!(0, 0).range_iter(3) {
+            let cell = HexCell::new(cell, 2);
+            let children = cell.children().unwrap();
+            assert_eq!(children[0], cell.to_resolution(1));
+            for child in children {
+                assert_eq!(child.resolution, 1);
+                assert_eq!(child.parent(), cell);
+            }
+        }
+    }
+
+    #[test]
+    fn to_resolution() {
+        let cell = HexCell::from(axial!(6, -2));
+        assert_eq!(cell.to_resolution(0), cell);
+        assert_eq!(cell.to_resolution(1), cell.parent());
+        assert_eq!(
+            HexCell::new(axial!(1, 0), 2).to_resolution(0),
+            HexCell::from(axial!(8, -5))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let cell = HexCell::new(axial!(1, 0), 2);
+        assert!(cell.contains(cell));
+        for child in cell.children().unwrap() {
+            assert!(cell.contains(child));
+            assert!(!child.contains(cell));
+        }
+        assert!(!cell.contains(HexCell::new(axial!(0, 0), 1)));
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn compact() {
+        assert_eq!(HexCell::compact(&[]), vec![]);
+
+        // A full resolution 2 cell and a stray hex next to it.
+        let cell = HexCell::new(axial!(0, 0), 2);
+        let mut cells = HexCell::uncompact(&[cell], 0).unwrap();
+        let stray = HexCell::from(axial!(10, 0));
+        cells.push(stray);
+        // Duplicates and cells covered by others are dropped.
+        cells.push(cell.children().unwrap()[3]);
+        cells.push(HexCell::from(axial!(0, 0)));
+
+        assert_eq!(HexCell::compact(&cells), vec![stray, cell]);
+
+        // Missing a single hex prevents compaction all the way up.
+        let mut cells = HexCell::uncompact(&[cell], 0).unwrap();
+        cells.retain(|cell| cell.coord != axial!(0, 0));
+        let compacted = HexCell::compact(&cells);
+        assert_eq!(compacted.len(), 6 + 6);
+        assert_eq!(HexCell::uncompact(&compacted, 0), Some(cells));
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn uncompact() {
+        assert_eq!(HexCell::uncompact(&[], 0), Some(vec![]));
+        assert_eq!(HexCell::uncompact(&[HexCell::from(axial!(0, 0))], 1), None);
+
+        let cell = HexCell::new(axial!(-1, 2), 2);
+        let cells = HexCell::uncompact(&[cell], 0).unwrap();
+        assert_eq!(cells.len(), 49);
+        assert!(cells.iter().all(|child| cell.contains(*child)));
+
+        let mut children = cell.children().unwrap();
+        children.sort();
+        assert_eq!(HexCell::uncompact(&[cell], 1), Some(children.to_vec()));
+    }
+
+    #[test]
+    fn fmt() {
+        let cell = HexCell::new(axial!(1, 0), 2);
+        assert_eq!(format!("{cell}"), "HexCell(2, 1, 0)");
+        assert_eq!("HexCell(2, 1, 0)".parse(), Ok(cell));
+        assert_eq!(
+            "HexCell(-2, 1, 0)".parse::<HexCell>(),
+            Err(ParseCoordError::Component)
+        );
+    }
+}
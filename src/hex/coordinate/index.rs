@@ -0,0 +1,364 @@
+//! Linear index encodings of hex coordinates.
+
+use super::{axial, offset, Axial, HexDirection, Offset, OffsetLayout};
+
+// Flipping the sign bit maps i32 onto u32 while keeping the ordering.
+const SIGN: u32 = 1 << 31;
+
+fn to_biased(value: i32) -> u32 {
+    value as u32 ^ SIGN
+}
+
+fn from_biased(value: u32) -> i32 {
+    (value ^ SIGN) as i32
+}
+
+// Spread the bits of a u32 out to the even bits of a u64.
+fn spread(value: u32) -> u64 {
+    let mut value = value as u64;
+    value = (value | (value << 16)) & 0x0000_FFFF_0000_FFFF;
+    value = (value | (value << 8)) & 0x00FF_00FF_00FF_00FF;
+    value = (value | (value << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+    (value | (value << 1)) & 0x5555_5555_5555_5555
+}
+
+// Gather the even bits of a u64 back into a u32.
+fn gather(value: u64) -> u32 {
+    let mut value = value & 0x5555_5555_5555_5555;
+    value = (value | (value >> 1)) & 0x3333_3333_3333_3333;
+    value = (value | (value >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    value = (value | (value >> 4)) & 0x00FF_00FF_00FF_00FF;
+    value = (value | (value >> 8)) & 0x0000_FFFF_0000_FFFF;
+    (value | (value >> 16)) as u32
+}
+
+// Integer square root, rounded down, by Newton's method.
+fn isqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value / 2 + 1;
+    let mut y = (x + value / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+// Index of the first coordinate of a ring, wide enough for any ring of i32 coordinates.
+fn ring_start(radius: u64) -> u128 {
+    match radius as u128 {
+        0 => 0,
+        radius => 3 * radius * (radius - 1) + 1,
+    }
+}
+
+impl Axial {
+    /// Pack the coordinate into a single integer.
+    ///
+    /// q occupies the upper 32 bits and r the lower, each biased so that ordering the packed
+    /// integers orders coordinates by q then r.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(Axial::from_packed(axial!(-3, 7).to_packed()), axial!(-3, 7));
+    /// assert!(axial!(-1, 5).to_packed() < axial!(0, -5).to_packed());
+    /// ```
+    pub fn to_packed(&self) -> u64 {
+        (to_biased(self.q) as u64) << 32 | to_biased(self.r) as u64
+    }
+
+    /// Unpack a coordinate packed by [`Axial::to_packed`].
+    pub fn from_packed(packed: u64) -> Self {
+        axial!(
+            from_biased((packed >> 32) as u32),
+            from_biased(packed as u32)
+        )
+    }
+
+    /// Encode the coordinate as the Morton, or Z-order, index of its offset coordinate.
+    ///
+    /// The bits of the column and row are interleaved, column in the even bits, so coordinates
+    /// near each other in the offset layout tend to be near each other in the index. The
+    /// components are biased like [`Axial::to_packed`] so the order is continuous across zero.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, OffsetLayout, axial};
+    ///
+    /// let index = axial!(2, -4).to_morton(OffsetLayout::OddR).unwrap();
+    /// assert_eq!(Axial::from_morton(index, OffsetLayout::OddR), Some(axial!(2, -4)));
+    /// assert_eq!(axial!(i32::MAX, 2).to_morton(OffsetLayout::OddR), None);
+    /// ```
+    pub fn to_morton(&self, layout: OffsetLayout) -> Option<u64> {
+        // Work in i64 so converting to the offset layout cannot overflow.
+        let (q, r) = (self.q as i64, self.r as i64);
+        let (col, row) = match layout {
+            OffsetLayout::OddR => (q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenR => (q + (r + (r & 1)) / 2, r),
+            OffsetLayout::OddQ => (q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenQ => (q, r + (q + (q & 1)) / 2),
+        };
+        let offset = offset!(i32::try_from(col).ok()?, i32::try_from(row).ok()?, layout);
+        Some(spread(to_biased(offset.col)) | spread(to_biased(offset.row)) << 1)
+    }
+
+    /// Decode a coordinate from an index produced by [`Axial::to_morton`].
+    ///
+    /// The layout must be the same one used to encode the index. Returns [`None`] if the
+    /// coordinate does not fit in an [`i32`].
+    pub fn from_morton(index: u64, layout: OffsetLayout) -> Option<Self> {
+        let col = from_biased(gather(index)) as i64;
+        let row = from_biased(gather(index >> 1)) as i64;
+        let (q, r) = match layout {
+            OffsetLayout::OddR => (col - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenR => (col - (row + (row & 1)) / 2, row),
+            OffsetLayout::OddQ => (col, row - (col - (col & 1)) / 2),
+            OffsetLayout::EvenQ => (col, row - (col + (col & 1)) / 2),
+        };
+        Some(axial!(i32::try_from(q).ok()?, i32::try_from(r).ok()?))
+    }
+
+    /// Encode the coordinate as its position in a spiral around origin.
+    ///
+    /// The spiral is the same as [`Axial::spiral`] starting [`Front`](super::HexDirection::Front) and
+    /// winding [`Clockwise`](crate::core::misc::Winding::Clockwise), origin has index 0.
+    ///
+    /// Returns [`None`] if the index does not fit in a [`u64`], which happens for coordinates
+    /// more than about 2.4 billion tiles from origin.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(0, 0).to_spiral_index(axial!(0, 0)), Some(0));
+    /// assert_eq!(axial!(1, 0).to_spiral_index(axial!(0, 0)), Some(1));
+    /// let coord = Axial::from_spiral_index(40, axial!(2, 2)).unwrap();
+    /// assert_eq!(coord.to_spiral_index(axial!(2, 2)), Some(40));
+    /// ```
+    pub fn to_spiral_index(&self, origin: Self) -> Option<u64> {
+        // Work in i64 so neither the offset nor the walk along a ring can overflow.
+        let (q, r) = (
+            self.q as i64 - origin.q as i64,
+            self.r as i64 - origin.r as i64,
+        );
+        let radius = q.abs().max(r.abs()).max((q + r).abs());
+        if radius == 0 {
+            return Some(0);
+        }
+
+        // Each side of the ring starts at a corner and walks two rotations from the corner.
+        let position = (0..6).find_map(|side| {
+            let corner = HexDirection::from(side).to_movement_vector();
+            let walk = HexDirection::from(side + 2).to_movement_vector();
+            let (dq, dr) = (q - radius * corner.q as i64, r - radius * corner.r as i64);
+            let step = dq.abs().max(dr.abs()).max((dq + dr).abs());
+            match step < radius && (dq, dr) == (step * walk.q as i64, step * walk.r as i64) {
+                true => Some(side as i64 * radius + step),
+                false => None,
+            }
+        })?;
+
+        u64::try_from(ring_start(radius as u64) + position as u128).ok()
+    }
+
+    /// Decode a coordinate from an index produced by [`Axial::to_spiral_index`].
+    ///
+    /// Returns [`None`] if the coordinate does not fit in an [`i32`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(Axial::from_spiral_index(1, axial!(0, 0)), Some(axial!(1, 0)));
+    /// assert_eq!(Axial::from_spiral_index(u64::MAX, axial!(0, 0)), None);
+    /// ```
+    pub fn from_spiral_index(index: u64, origin: Self) -> Option<Self> {
+        if index == 0 {
+            return Some(origin);
+        }
+
+        // Invert ring_start, correcting for any rounding of the integer square root.
+        let mut radius = isqrt(index / 3);
+        while ring_start(radius) > index as u128 {
+            radius -= 1;
+        }
+        while ring_start(radius + 1) <= index as u128 {
+            radius += 1;
+        }
+
+        // Every coordinate on the ring has components no larger than the radius.
+        let radius = i32::try_from(radius).ok()?;
+        let position = index - ring_start(radius as u64) as u64;
+        let (side, step) = (
+            (position / radius as u64) as i32,
+            (position % radius as u64) as i32,
+        );
+
+        let local = axial!(0, 0)
+            .make_vector(radius, side)
+            .make_vector(step, side + 2);
+        origin.checked_add(local)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::misc::Winding;
+
+    #[test]
+    fn packed() {
+        assert_eq!(axial!(0, 0).to_packed(), 0x8000_0000_8000_0000);
+        for coord in [
+            axial!(i32::MIN, i32::MAX),
+            axial!(i32::MAX, i32::MIN),
+            axial!(-1, -1),
+            axial!(12, -99),
+        ] {
+            assert_eq!(Axial::from_packed(coord.to_packed()), coord);
+        }
+
+        // Packed order is the (q, r) order.
+        let coords = axial!(0, 0).range_iter(3);
+        for a in coords.clone() {
+            for b in coords.clone() {
+                assert_eq!(
+                    a.to_packed().cmp(&b.to_packed()),
+                    (a.q, a.r).cmp(&(b.q, b.r))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn morton() {
+        for layout in [
+            OffsetLayout::OddR,
+            OffsetLayout::EvenR,
+            OffsetLayout::OddQ,
+            OffsetLayout::EvenQ,
+        ] {
+            for coord in axial!(0, 0)
+                .range_iter(10)
+                .chain([axial!(i32::MAX / 2, 0), axial!(0, i32::MIN / 2)])
+            {
+                let index = coord.to_morton(layout).unwrap();
+                assert_eq!(Axial::from_morton(index, layout), Some(coord));
+            }
+        }
+
+        // The four offset coordinates of a 2x2 block aligned to the bias are consecutive.
+        let block = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(col, row)| {
+            offset!(col, row, OffsetLayout::OddR)
+                .to_axial()
+                .to_morton(OffsetLayout::OddR)
+                .unwrap()
+        });
+        let base = block[0];
+        assert_eq!(block, [base, base + 1, base + 2, base + 3]);
+    }
+
+    #[test]
+    fn integer_sqrt() {
+        for value in 0..1_000 {
+            let root = isqrt(value);
+            assert!(root * root <= value && (root + 1) * (root + 1) > value);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn morton_bounds() {
+        let layouts = [
+            OffsetLayout::OddR,
+            OffsetLayout::EvenR,
+            OffsetLayout::OddQ,
+            OffsetLayout::EvenQ,
+        ];
+        let corners = [
+            (i32::MIN, i32::MIN),
+            (i32::MIN, i32::MAX),
+            (i32::MAX, i32::MIN),
+            (i32::MAX, i32::MAX),
+        ];
+
+        // Corners of the offset layout round trip whenever their axial coordinate fits.
+        let mut decoded = 0;
+        for layout in layouts {
+            for (col, row) in corners {
+                let index = spread(to_biased(col)) | spread(to_biased(row)) << 1;
+                if let Some(coord) = Axial::from_morton(index, layout) {
+                    assert_eq!(coord.to_morton(layout), Some(index));
+                    decoded += 1;
+                }
+            }
+        }
+        assert_eq!(decoded, 8);
+
+        // Axial coordinates whose offset column or row does not fit.
+        assert_eq!(axial!(i32::MAX, 2).to_morton(OffsetLayout::OddR), None);
+        assert_eq!(axial!(2, i32::MAX).to_morton(OffsetLayout::OddQ), None);
+        assert_eq!(axial!(i32::MIN, -2).to_morton(OffsetLayout::EvenR), None);
+
+        // The extremes of axial coordinates that do fit, including ones where converting in i32
+        // would overflow along the way.
+        let index = axial!(0, i32::MAX).to_morton(OffsetLayout::EvenR).unwrap();
+        assert_eq!(
+            Axial::from_morton(index, OffsetLayout::EvenR),
+            Some(axial!(0, i32::MAX))
+        );
+        for coord in [
+            axial!(i32::MAX, 0),
+            axial!(i32::MIN, 0),
+            axial!(0, i32::MIN),
+            axial!(i32::MAX, i32::MIN),
+        ] {
+            let index = coord.to_morton(OffsetLayout::OddR).unwrap();
+            assert_eq!(Axial::from_morton(index, OffsetLayout::OddR), Some(coord));
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn spiral_index() {
+        let origin = axial!(3, -2);
+        let spiral = origin.spiral(4, HexDirection::Front, Winding::Clockwise);
+
+        for (index, coord) in spiral.iter().enumerate() {
+            assert_eq!(coord.to_spiral_index(origin), Some(index as u64));
+            assert_eq!(Axial::from_spiral_index(index as u64, origin), Some(*coord));
+        }
+
+        for index in [1_000, 123_456, 9_999_999] {
+            let coord = Axial::from_spiral_index(index, origin).unwrap();
+            assert_eq!(coord.to_spiral_index(origin), Some(index));
+        }
+    }
+
+    #[test]
+    fn spiral_index_bounds() {
+        // The furthest coordinates from origin still fit.
+        let far = axial!(i32::MAX, 0);
+        let index = far.to_spiral_index(axial!(0, 0)).unwrap();
+        assert_eq!(Axial::from_spiral_index(index, axial!(0, 0)), Some(far));
+
+        // Too far to index.
+        assert_eq!(
+            axial!(i32::MAX, i32::MAX).to_spiral_index(axial!(i32::MIN, 0)),
+            None
+        );
+
+        // Rings beyond the range of i32 and offsets that overflow.
+        assert_eq!(Axial::from_spiral_index(u64::MAX, axial!(0, 0)), None);
+        assert_eq!(Axial::from_spiral_index(1, axial!(i32::MAX, 0)), None);
+        assert_eq!(
+            Axial::from_spiral_index(1, axial!(i32::MAX - 1, 0)),
+            Some(axial!(i32::MAX, 0))
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Lazy iterators over hex coordinate queries.
+//!
+//! These iterators do not allocate and report an exact [`Iterator::size_hint`].
+
+use crate::lib::*;
+
+use super::{axial, Axial};
+use crate::core::int::SignedInt;
+
+/// Iterator over every coordinate within a range, see [`Axial::range_iter`].
+#[derive(Clone, Debug)]
+pub struct AxialRange<T = i32> {
+    center: Axial<T>,
+    range: T,
+    q: T,
+    r: T,
+    remaining: usize,
+}
+
+impl<T: SignedInt> AxialRange<T> {
+    pub(super) fn new(center: Axial<T>, range: T) -> Self {
+        let remaining = match range < T::ZERO {
+            true => 0,
+            // A range this large could never be collected, so the count saturates.
+            false => {
+                let range = usize::try_from(range.to_i128()).unwrap_or(usize::MAX);
+                range
+                    .saturating_mul(range.saturating_add(1))
+                    .saturating_mul(3)
+                    .saturating_add(1)
+            }
+        };
+
+        let mut ret = Self {
+            center,
+            range,
+            q: T::ZERO,
+            r: T::ZERO,
+            remaining,
+        };
+        // A negative range is empty, and may not be negated.
+        if remaining > 0 {
+            ret.q = -range;
+            ret.r = ret.r_min();
+        }
+        ret
+    }
+
+    // The bounds of r for the current q, branching on the sign of q so that no intermediate
+    // exceeds the range.
+    fn r_min(&self) -> T {
+        match self.q >= T::ZERO {
+            true => -self.range,
+            false => -(self.q + self.range),
+        }
+    }
+
+    fn r_max(&self) -> T {
+        match self.q <= T::ZERO {
+            true => self.range,
+            false => self.range - self.q,
+        }
+    }
+}
+
+impl<T: SignedInt> Iterator for AxialRange<T> {
+    type Item = Axial<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let ret = self.center + axial!(self.q, self.r);
+        self.remaining -= 1;
+
+        // Only step once another coordinate follows, the last one is on the bounds of T for the
+        // largest ranges.
+        if self.remaining > 0 {
+            if self.r == self.r_max() {
+                self.q += T::ONE;
+                self.r = self.r_min();
+            } else {
+                self.r += T::ONE;
+            }
+        }
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: SignedInt> ExactSizeIterator for AxialRange<T> {}
+impl<T: SignedInt> FusedIterator for AxialRange<T> {}
+
+/// Iterator over the coordinates forming a line, see [`Axial::line_iter`].
+#[derive(Clone, Debug)]
+pub struct AxialLine<T = i32> {
+    a: Axial<T>,
+    b: Axial<T>,
+    dist: T,
+    step: T,
+}
+
+impl<T: SignedInt> AxialLine<T> {
+    pub(super) fn new(a: Axial<T>, b: Axial<T>) -> Self {
+        Self {
+            a,
+            b,
+            dist: a.distance(b),
+            step: T::ZERO,
+        }
+    }
+}
+
+impl<T: SignedInt> Iterator for AxialLine<T> {
+    type Item = Axial<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step > self.dist {
+            return None;
+        }
+
+        // A zero length line would otherwise interpolate with NaN.
+        let t = match self.dist == T::ZERO {
+            true => 0.0,
+            false => self.step.to_f64() / self.dist.to_f64(),
+        };
+
+        self.step += T::ONE;
+        Some(self.a.lerp(self.b, t))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.dist.to_i128() + 1 - self.step.to_i128();
+        let remaining = usize::try_from(remaining).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: SignedInt> ExactSizeIterator for AxialLine<T> {}
+impl<T: SignedInt> FusedIterator for AxialLine<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_size_hint() {
+        for range in 0..5 {
+            let mut iter = axial!(2, -1).range_iter(range);
+            let mut len = iter.len();
+            assert_eq!(len, (3 * range * (range + 1) + 1) as usize);
+
+            while let Some(coord) = iter.next() {
+                assert!(coord.distance(axial!(2, -1)) <= range);
+                len -= 1;
+                assert_eq!(iter.size_hint(), (len, Some(len)));
+            }
+            assert_eq!(len, 0);
+        }
+
+        assert_eq!(axial!(0, 0).range_iter(-1).next(), None);
+    }
+
+    #[test]
+    fn range_narrow_components() {
+        // Ranges over half the width of the component still fit every coordinate.
+        let center = Axial::<i8>::default();
+        assert_eq!(center.range_iter(100).count(), 3 * 100 * 101 + 1);
+
+        let mut iter = center.range_iter(i8::MAX);
+        assert_eq!(iter.next(), Some(axial!(-i8::MAX, 0)));
+        assert_eq!(iter.last(), Some(axial!(i8::MAX, 0)));
+        assert_eq!(center.range_iter(i8::MIN).next(), None);
+
+        let mut iter = Axial::<i16>::default().range_iter(20000);
+        assert_eq!(iter.next(), Some(axial!(-20000, 0)));
+        assert_eq!(iter.next(), Some(axial!(-20000, 1)));
+    }
+
+    #[test]
+    fn line_size_hint() {
+        let mut iter = axial!(-1, -1).line_iter(axial!(1, 1));
+        assert_eq!(iter.len(), 5);
+        iter.next();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.last(), Some(axial!(1, 1)));
+
+        let mut iter = axial!(3, 2).line_iter(axial!(3, 2));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(axial!(3, 2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn expected_coords() {
+        let coord = axial!(1, -2);
+        assert!(coord.range_iter(1).eq([
+            axial!(0, -2),
+            axial!(0, -1),
+            axial!(1, -3),
+            axial!(1, -2),
+            axial!(1, -1),
+            axial!(2, -3),
+            axial!(2, -2),
+        ]));
+        assert!(coord.line_iter(axial!(-2, 0)).eq([
+            axial!(1, -2),
+            axial!(0, -1),
+            axial!(-1, -1),
+            axial!(-2, 0),
+        ]));
+    }
+}
@@ -0,0 +1,303 @@
+//! Human readable labels for hexes on printed boards.
+
+use crate::core::parse::ParseCoordError;
+use crate::lib::*;
+
+use super::{offset, Axial, Offset, OffsetLayout};
+
+/// How the column and row of a label are written.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum LabelScheme {
+    /// Column then row as zero padded numbers, the classic wargame numbering, e.g. `0304`.
+    Numeric {
+        /// Digits used for each of the column and row.
+        digits: u32,
+    },
+    /// Column as letters then row as a number, like a chess board, e.g. `C7`.
+    ///
+    /// Columns past `Z` continue with `AA`, `AB` and so on.
+    Alphanumeric,
+}
+
+impl Default for LabelScheme {
+    fn default() -> Self {
+        LabelScheme::Numeric { digits: 2 }
+    }
+}
+
+/// The label of a hex, displays as text in its [`LabelScheme`].
+///
+/// Produced by [`Labeling::label`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct HexLabel {
+    /// Number of the column as written.
+    pub col: u32,
+    /// Number of the row as written.
+    pub row: u32,
+    /// How the label is written.
+    pub scheme: LabelScheme,
+}
+
+/// Maps hexes to labels and back through an [`OffsetLayout`].
+///
+/// The column and row numbers written on a label are the offset column and row plus `first`,
+/// printed boards usually start counting at 1. Hexes left of or above the first column or row
+/// have no label.
+///
+/// # Example
+/// ```
+/// use gridava::hex::coordinate::{Axial, LabelScheme, Labeling, OffsetLayout, axial};
+///
+/// let wargame = Labeling::new(OffsetLayout::OddQ, LabelScheme::Numeric { digits: 2 });
+/// let coord = wargame.parse("0304").unwrap();
+/// assert_eq!(wargame.label(coord).unwrap().to_string(), "0304");
+///
+/// let chess = Labeling::new(OffsetLayout::OddR, LabelScheme::Alphanumeric);
+/// assert_eq!(chess.label(axial!(0, 0)).unwrap().to_string(), "A1");
+/// assert_eq!(chess.parse("C7"), Ok(axial!(-1, 6)));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct Labeling {
+    /// Layout the column and row are taken from.
+    pub layout: OffsetLayout,
+    /// How labels are written.
+    pub scheme: LabelScheme,
+    /// Number written for offset column and row 0.
+    pub first: u32,
+}
+
+impl Labeling {
+    /// Constructor for a labeling counting from 1.
+    pub const fn new(layout: OffsetLayout, scheme: LabelScheme) -> Self {
+        Self {
+            layout,
+            scheme,
+            first: 1,
+        }
+    }
+
+    /// Get the label of a hex.
+    ///
+    /// Produces [`None`] if the hex is before the first column or row, or its numbers do not fit
+    /// the digits of a [`LabelScheme::Numeric`] scheme.
+    pub fn label(&self, coord: Axial) -> Option<HexLabel> {
+        let offset = coord.to_offset(self.layout);
+        let number = |value: i32| match value {
+            0.. => u32::try_from(i64::from(value) + i64::from(self.first)).ok(),
+            _ => None,
+        };
+
+        let label = HexLabel {
+            col: number(offset.col)?,
+            row: number(offset.row)?,
+            scheme: self.scheme,
+        };
+        match label.is_representable() {
+            true => Some(label),
+            false => None,
+        }
+    }
+
+    /// Get the hex a label refers to.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, LabelScheme, Labeling, OffsetLayout, axial};
+    ///
+    /// let labeling = Labeling::new(OffsetLayout::OddR, LabelScheme::Numeric { digits: 2 });
+    /// assert_eq!(labeling.parse("0101"), Ok(axial!(0, 0)));
+    /// assert!(labeling.parse("101").is_err());
+    /// ```
+    pub fn parse(&self, label: &str) -> Result<Axial, ParseCoordError> {
+        let label = HexLabel::parse(label, self.scheme)?;
+        let number = |value: u32| {
+            value
+                .checked_sub(self.first)
+                .and_then(|value| i32::try_from(value).ok())
+                .ok_or(ParseCoordError::Invalid)
+        };
+
+        Ok(offset!(number(label.col)?, number(label.row)?, self.layout).to_axial())
+    }
+}
+
+impl HexLabel {
+    /// Parse the text of a label written in a scheme.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{HexLabel, LabelScheme};
+    ///
+    /// let label = HexLabel::parse("AB12", LabelScheme::Alphanumeric).unwrap();
+    /// assert_eq!((label.col, label.row), (28, 12));
+    /// ```
+    pub fn parse(text: &str, scheme: LabelScheme) -> Result<Self, ParseCoordError> {
+        let text = text.trim();
+        let (col, row) = match scheme {
+            LabelScheme::Numeric { digits } => {
+                if text.len() != 2 * digits as usize || !text.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseCoordError::Format);
+                }
+                let (col, row) = text.split_at(digits as usize);
+                (parse_number(col)?, parse_number(row)?)
+            }
+            LabelScheme::Alphanumeric => {
+                let split = text
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .ok_or(ParseCoordError::Format)?;
+                let (letters, row) = text.split_at(split);
+                if letters.is_empty() || !row.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseCoordError::Format);
+                }
+                (parse_letters(letters)?, parse_number(row)?)
+            }
+        };
+
+        Ok(Self { col, row, scheme })
+    }
+
+    // Whether the scheme can write the numbers of this label.
+    fn is_representable(&self) -> bool {
+        match self.scheme {
+            LabelScheme::Numeric { digits } => {
+                let limit = 10u64.checked_pow(digits).unwrap_or(u64::MAX);
+                u64::from(self.col) < limit && u64::from(self.row) < limit
+            }
+            LabelScheme::Alphanumeric => self.col > 0,
+        }
+    }
+}
+
+fn parse_number(text: &str) -> Result<u32, ParseCoordError> {
+    text.parse().map_err(|_| ParseCoordError::Component)
+}
+
+// Columns are bijective base 26, A = 1, Z = 26, AA = 27.
+fn parse_letters(text: &str) -> Result<u32, ParseCoordError> {
+    text.bytes().try_fold(0u32, |col, letter| {
+        col.checked_mul(26)
+            .and_then(|col| col.checked_add(u32::from(letter.to_ascii_uppercase() - b'A' + 1)))
+            .ok_or(ParseCoordError::Component)
+    })
+}
+
+impl Display for HexLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.scheme {
+            LabelScheme::Numeric { digits } => {
+                let width = digits as usize;
+                write!(f, "{:0width$}{:0width$}", self.col, self.row)
+            }
+            LabelScheme::Alphanumeric => {
+                // Letters are produced least significant first, 7 letters cover every u32.
+                let mut letters = [0u8; 7];
+                let mut len = 0;
+                let mut col = self.col;
+                while col > 0 {
+                    col -= 1;
+                    letters[len] = b'A' + (col % 26) as u8;
+                    col /= 26;
+                    len += 1;
+                }
+                for letter in letters[..len].iter().rev() {
+                    write!(f, "{}", *letter as char)?;
+                }
+                write!(f, "{}", self.row)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axial;
+
+    #[test]
+    fn numeric() {
+        let labeling = Labeling::new(OffsetLayout::OddQ, LabelScheme::Numeric { digits: 2 });
+        assert_eq!(format!("{}", labeling.label(axial!(0, 0)).unwrap()), "0101");
+        assert_eq!(labeling.parse("0101"), Ok(axial!(0, 0)));
+
+        // Column 3, row 4 counting from 1.
+        let coord = offset!(2, 3, OffsetLayout::OddQ).to_axial();
+        assert_eq!(format!("{}", labeling.label(coord).unwrap()), "0304");
+        assert_eq!(labeling.parse("0304"), Ok(coord));
+
+        assert_eq!(labeling.label(axial!(-1, 0)), None);
+        assert_eq!(labeling.label(axial!(99, 0)), None);
+        assert_eq!(labeling.parse("0304x"), Err(ParseCoordError::Format));
+        assert_eq!(labeling.parse("03a4"), Err(ParseCoordError::Format));
+        assert_eq!(labeling.parse("0003"), Err(ParseCoordError::Invalid));
+
+        let labeling = Labeling {
+            first: 0,
+            ..Labeling::new(OffsetLayout::EvenR, LabelScheme::Numeric { digits: 3 })
+        };
+        assert_eq!(
+            format!("{}", labeling.label(axial!(0, 0)).unwrap()),
+            "000000"
+        );
+        assert_eq!(
+            labeling.parse("012003"),
+            Ok(offset!(12, 3, OffsetLayout::EvenR).to_axial())
+        );
+    }
+
+    #[test]
+    fn alphanumeric() {
+        let labeling = Labeling::new(OffsetLayout::OddR, LabelScheme::Alphanumeric);
+        assert_eq!(format!("{}", labeling.label(axial!(0, 0)).unwrap()), "A1");
+
+        for (col, letters) in [
+            (0, "A"),
+            (25, "Z"),
+            (26, "AA"),
+            (27, "AB"),
+            (701, "ZZ"),
+            (702, "AAA"),
+        ] {
+            let coord = offset!(col, 9, OffsetLayout::OddR).to_axial();
+            let text = format!("{}", labeling.label(coord).unwrap());
+            assert_eq!(text, format!("{letters}10"));
+            assert_eq!(labeling.parse(&text), Ok(coord));
+        }
+
+        assert_eq!(labeling.parse("c7"), labeling.parse("C7"));
+        assert_eq!(labeling.parse("7"), Err(ParseCoordError::Format));
+        assert_eq!(labeling.parse("C"), Err(ParseCoordError::Format));
+        assert_eq!(labeling.parse("C7D"), Err(ParseCoordError::Format));
+
+        // Counting from 0 leaves the first column without letters.
+        let labeling = Labeling {
+            first: 0,
+            ..labeling
+        };
+        assert_eq!(labeling.label(axial!(0, 1)), None);
+        assert_eq!(format!("{}", labeling.label(axial!(1, 0)).unwrap()), "A0");
+    }
+
+    #[test]
+    fn roundtrip() {
+        for scheme in [
+            LabelScheme::Numeric { digits: 2 },
+            LabelScheme::Alphanumeric,
+        ] {
+            for layout in [
+                OffsetLayout::OddR,
+                OffsetLayout::EvenR,
+                OffsetLayout::OddQ,
+                OffsetLayout::EvenQ,
+            ] {
+                let labeling = Labeling::new(layout, scheme);
+                for coord in axial!(10, 10).range_iter(8) {
+                    let label = labeling.label(coord).unwrap();
+                    assert_eq!(labeling.parse(&format!("{label}")), Ok(coord));
+                }
+            }
+        }
+    }
+}
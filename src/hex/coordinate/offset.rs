@@ -0,0 +1,495 @@
+//! Offset coordinate systems for hex based grids.
+
+use crate::core::coordinate::{Distance, Line, Neighbors, Range};
+use crate::core::parse::{self, ParseCoordError};
+use crate::lib::*;
+
+use super::{axial, Axial, AxialLine, AxialRange, HexDirection};
+
+/// Parity of the rows or columns that are shoved in an offset layout.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum OffsetParity {
+    /// Odd rows or columns are shoved.
+    #[default]
+    Odd,
+    /// Even rows or columns are shoved.
+    Even,
+}
+
+/// Describes how an offset coordinate maps onto hexagons.
+///
+/// Row layouts, `R`, shove every other row by half a hexagon and are used with pointy top hexagons.
+/// Column layouts, `Q`, shove every other column and are used with flat top hexagons.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum OffsetLayout {
+    /// Odd rows are shoved right.
+    #[default]
+    OddR,
+    /// Even rows are shoved right.
+    EvenR,
+    /// Odd columns are shoved down.
+    OddQ,
+    /// Even columns are shoved down.
+    EvenQ,
+}
+
+impl OffsetLayout {
+    /// The parity of the shoved rows or columns.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{OffsetLayout, OffsetParity};
+    ///
+    /// assert_eq!(OffsetLayout::EvenQ.parity(), OffsetParity::Even);
+    /// ```
+    pub fn parity(&self) -> OffsetParity {
+        match self {
+            OffsetLayout::OddR | OffsetLayout::OddQ => OffsetParity::Odd,
+            OffsetLayout::EvenR | OffsetLayout::EvenQ => OffsetParity::Even,
+        }
+    }
+
+    /// Whether this layout shoves rows, as opposed to columns.
+    pub fn is_row_layout(&self) -> bool {
+        matches!(self, OffsetLayout::OddR | OffsetLayout::EvenR)
+    }
+}
+
+impl Display for OffsetLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OffsetLayout::OddR => "OddR",
+            OffsetLayout::EvenR => "EvenR",
+            OffsetLayout::OddQ => "OddQ",
+            OffsetLayout::EvenQ => "EvenQ",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for OffsetLayout {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "OddR" => Ok(OffsetLayout::OddR),
+            "EvenR" => Ok(OffsetLayout::EvenR),
+            "OddQ" => Ok(OffsetLayout::OddQ),
+            "EvenQ" => Ok(OffsetLayout::EvenQ),
+            _ => Err(ParseCoordError::Component),
+        }
+    }
+}
+
+/// Offset coordinates for hexagon grids.
+///
+/// Offset coordinates index hexagons by column and row, as in a 2D array, with every other row or column
+/// shoved by half a hexagon. Which lines are shoved is described by the [`OffsetLayout`].
+///
+/// Conversions to and from [`Axial`] are lossless.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct Offset {
+    /// Column of the coordinate
+    pub col: i32,
+    /// Row of the coordinate
+    pub row: i32,
+    /// Layout the column and row are expressed in
+    pub layout: OffsetLayout,
+}
+
+/// Iterator over the coordinates within a range, see [`Range`] for [`Offset`].
+pub type OffsetRange = iter::Map<
+    iter::Zip<AxialRange, iter::Repeat<OffsetLayout>>,
+    fn((Axial, OffsetLayout)) -> Offset,
+>;
+
+/// Iterator over the coordinates forming a line, see [`Line`] for [`Offset`].
+pub type OffsetLine = iter::Map<
+    iter::Zip<AxialLine, iter::Repeat<OffsetLayout>>,
+    fn((Axial, OffsetLayout)) -> Offset,
+>;
+
+/// Helper macro to create [`Offset`] structs.
+#[macro_export]
+macro_rules! offset {
+    ($col:expr, $row:expr, $layout:expr) => {
+        Offset {
+            col: $col,
+            row: $row,
+            layout: $layout,
+        }
+    };
+}
+pub use offset;
+
+impl From<Offset> for Axial {
+    fn from(value: Offset) -> Self {
+        value.to_axial()
+    }
+}
+
+impl Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Offset({}, {}, {})", self.col, self.row, self.layout)
+    }
+}
+
+/// Parses the form produced by [`Display`], e.g. `Offset(3, 4, OddR)`.
+impl FromStr for Offset {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [col, row, layout] = parse::components(s, "Offset")?;
+        Ok(offset!(
+            parse::component(col)?,
+            parse::component(row)?,
+            parse::component(layout)?
+        ))
+    }
+}
+
+impl Offset {
+    /// Convert an [`Axial`] coordinate into an offset coordinate of the given layout.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Offset, offset, OffsetLayout};
+    ///
+    /// let coord = Offset::from_axial(axial!(-1, 3), OffsetLayout::OddR);
+    /// assert_eq!(coord, offset!(0, 3, OffsetLayout::OddR));
+    /// ```
+    pub fn from_axial(coord: Axial, layout: OffsetLayout) -> Self {
+        match layout {
+            OffsetLayout::OddR => offset!(coord.q + (coord.r - (coord.r & 1)) / 2, coord.r, layout),
+            OffsetLayout::EvenR => {
+                offset!(coord.q + (coord.r + (coord.r & 1)) / 2, coord.r, layout)
+            }
+            OffsetLayout::OddQ => offset!(coord.q, coord.r + (coord.q - (coord.q & 1)) / 2, layout),
+            OffsetLayout::EvenQ => {
+                offset!(coord.q, coord.r + (coord.q + (coord.q & 1)) / 2, layout)
+            }
+        }
+    }
+
+    /// Convert to an [`Axial`] coordinate.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Offset, offset, OffsetLayout};
+    ///
+    /// assert_eq!(offset!(0, 3, OffsetLayout::OddR).to_axial(), axial!(-1, 3));
+    /// ```
+    pub fn to_axial(&self) -> Axial {
+        match self.layout {
+            OffsetLayout::OddR => axial!(self.col - (self.row - (self.row & 1)) / 2, self.row),
+            OffsetLayout::EvenR => axial!(self.col - (self.row + (self.row & 1)) / 2, self.row),
+            OffsetLayout::OddQ => axial!(self.col, self.row - (self.col - (self.col & 1)) / 2),
+            OffsetLayout::EvenQ => axial!(self.col, self.row - (self.col + (self.col & 1)) / 2),
+        }
+    }
+
+    /// Express this coordinate in another layout.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Offset, offset, OffsetLayout};
+    ///
+    /// let coord = offset!(0, 3, OffsetLayout::OddR).to_layout(OffsetLayout::EvenR);
+    /// assert_eq!(coord, offset!(1, 3, OffsetLayout::EvenR));
+    /// ```
+    pub fn to_layout(&self, layout: OffsetLayout) -> Self {
+        Self::from_axial(self.to_axial(), layout)
+    }
+
+    /// Get a neighbor coordinate given a direction.
+    ///
+    /// The row, or column, parity of this coordinate is accounted for.
+    ///
+    /// See [`HexDirection`] for a reference of directionality.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{HexDirection, Offset, offset, OffsetLayout};
+    ///
+    /// // Even row, the row below is not shoved.
+    /// let coord = offset!(0, 0, OffsetLayout::OddR).neighbor(HexDirection::FrontRight);
+    /// assert_eq!(coord, offset!(0, 1, OffsetLayout::OddR));
+    ///
+    /// // Odd row, the row below is shoved.
+    /// let coord = offset!(0, 1, OffsetLayout::OddR).neighbor(HexDirection::FrontRight);
+    /// assert_eq!(coord, offset!(1, 2, OffsetLayout::OddR));
+    /// ```
+    pub fn neighbor(&self, direction: HexDirection) -> Self {
+        Self::from_axial(self.to_axial().neighbor(direction), self.layout)
+    }
+
+    /// Get all the neighbors for this coordinate.
+    ///
+    /// The neighbors are in the order of [`HexDirection`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Offset, offset, OffsetLayout};
+    ///
+    /// let neighbors = offset!(2, 3, OffsetLayout::EvenQ).neighbors();
+    /// ```
+    pub fn neighbors(&self) -> [Self; 6] {
+        self.to_axial()
+            .neighbors()
+            .map(|coord| Self::from_axial(coord, self.layout))
+    }
+
+    /// Compute distance between two coordinates.
+    ///
+    /// The coordinates do not need to share a layout.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Offset, offset, OffsetLayout};
+    ///
+    /// let dist = offset!(0, 0, OffsetLayout::OddR).distance(offset!(3, 3, OffsetLayout::OddR));
+    /// assert_eq!(dist, 5);
+    /// ```
+    pub fn distance(&self, b: Self) -> i32 {
+        self.to_axial().distance(b.to_axial())
+    }
+}
+
+impl Neighbors for Offset {
+    type NeighborIter = array::IntoIter<Self, 6>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        Offset::neighbors(self).into_iter()
+    }
+}
+
+impl Distance for Offset {
+    fn distance(&self, b: Self) -> u32 {
+        Offset::distance(self, b).unsigned_abs()
+    }
+}
+
+/// The coordinates are in the layout of self.
+impl Range for Offset {
+    type RangeIter = OffsetRange;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        let to_offset: fn((Axial, OffsetLayout)) -> Offset =
+            |(coord, layout)| Offset::from_axial(coord, layout);
+        self.to_axial()
+            .range_iter(range)
+            .zip(iter::repeat(self.layout))
+            .map(to_offset)
+    }
+}
+
+/// The coordinates are in the layout of self, `b` does not need to share it.
+impl Line for Offset {
+    type LineIter = OffsetLine;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        let to_offset: fn((Axial, OffsetLayout)) -> Offset =
+            |(coord, layout)| Offset::from_axial(coord, layout);
+        self.to_axial()
+            .line_iter(b.to_axial())
+            .zip(iter::repeat(self.layout))
+            .map(to_offset)
+    }
+}
+
+impl Axial {
+    /// Convert to an [`Offset`] coordinate of the given layout.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial, Offset, offset, OffsetLayout};
+    ///
+    /// assert_eq!(axial!(2, -3).to_offset(OffsetLayout::OddQ), offset!(2, -2, OffsetLayout::OddQ));
+    /// ```
+    pub fn to_offset(&self, layout: OffsetLayout) -> Offset {
+        Offset::from_axial(*self, layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [OffsetLayout; 4] = [
+        OffsetLayout::OddR,
+        OffsetLayout::EvenR,
+        OffsetLayout::OddQ,
+        OffsetLayout::EvenQ,
+    ];
+
+    #[test]
+    fn offset_macro() {
+        assert_eq!(
+            Offset {
+                col: 1,
+                row: 2,
+                layout: OffsetLayout::EvenQ
+            },
+            offset!(1, 2, OffsetLayout::EvenQ)
+        );
+    }
+
+    #[test]
+    fn parity() {
+        assert_eq!(OffsetLayout::OddR.parity(), OffsetParity::Odd);
+        assert_eq!(OffsetLayout::EvenR.parity(), OffsetParity::Even);
+        assert_eq!(OffsetLayout::OddQ.parity(), OffsetParity::Odd);
+        assert_eq!(OffsetLayout::EvenQ.parity(), OffsetParity::Even);
+    }
+
+    #[test]
+    fn is_row_layout() {
+        assert!(OffsetLayout::OddR.is_row_layout());
+        assert!(OffsetLayout::EvenR.is_row_layout());
+        assert!(!OffsetLayout::OddQ.is_row_layout());
+        assert!(!OffsetLayout::EvenQ.is_row_layout());
+    }
+
+    #[test]
+    fn from_axial() {
+        assert_eq!(
+            Offset::from_axial(axial!(-1, 3), OffsetLayout::OddR),
+            offset!(0, 3, OffsetLayout::OddR)
+        );
+        assert_eq!(
+            Offset::from_axial(axial!(-1, 3), OffsetLayout::EvenR),
+            offset!(1, 3, OffsetLayout::EvenR)
+        );
+        assert_eq!(
+            Offset::from_axial(axial!(3, -1), OffsetLayout::OddQ),
+            offset!(3, 0, OffsetLayout::OddQ)
+        );
+        assert_eq!(
+            Offset::from_axial(axial!(3, -1), OffsetLayout::EvenQ),
+            offset!(3, 1, OffsetLayout::EvenQ)
+        );
+        assert_eq!(
+            Offset::from_axial(axial!(1, -3), OffsetLayout::OddR),
+            offset!(-1, -3, OffsetLayout::OddR)
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn to_axial() {
+        for layout in LAYOUTS {
+            for coord in axial!(0, 0).range(6) {
+                assert_eq!(Offset::from_axial(coord, layout).to_axial(), coord);
+                assert_eq!(Axial::from(coord.to_offset(layout)), coord);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn to_layout() {
+        for from in LAYOUTS {
+            for to in LAYOUTS {
+                for coord in axial!(0, 0).range(4) {
+                    let converted = coord.to_offset(from).to_layout(to);
+                    assert_eq!(converted.layout, to);
+                    assert_eq!(converted.to_axial(), coord);
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn neighbor() {
+        // Odd-r, even row then odd row
+        assert_eq!(
+            offset!(0, 0, OffsetLayout::OddR).neighbors(),
+            [
+                offset!(1, 0, OffsetLayout::OddR),
+                offset!(0, 1, OffsetLayout::OddR),
+                offset!(-1, 1, OffsetLayout::OddR),
+                offset!(-1, 0, OffsetLayout::OddR),
+                offset!(-1, -1, OffsetLayout::OddR),
+                offset!(0, -1, OffsetLayout::OddR),
+            ]
+        );
+        assert_eq!(
+            offset!(0, 1, OffsetLayout::OddR).neighbors(),
+            [
+                offset!(1, 1, OffsetLayout::OddR),
+                offset!(1, 2, OffsetLayout::OddR),
+                offset!(0, 2, OffsetLayout::OddR),
+                offset!(-1, 1, OffsetLayout::OddR),
+                offset!(0, 0, OffsetLayout::OddR),
+                offset!(1, 0, OffsetLayout::OddR),
+            ]
+        );
+
+        // Even-q, even column then odd column
+        assert_eq!(
+            offset!(0, 0, OffsetLayout::EvenQ).neighbors(),
+            [
+                offset!(1, 1, OffsetLayout::EvenQ),
+                offset!(0, 1, OffsetLayout::EvenQ),
+                offset!(-1, 1, OffsetLayout::EvenQ),
+                offset!(-1, 0, OffsetLayout::EvenQ),
+                offset!(0, -1, OffsetLayout::EvenQ),
+                offset!(1, 0, OffsetLayout::EvenQ),
+            ]
+        );
+        assert_eq!(
+            offset!(1, 0, OffsetLayout::EvenQ).neighbors(),
+            [
+                offset!(2, 0, OffsetLayout::EvenQ),
+                offset!(1, 1, OffsetLayout::EvenQ),
+                offset!(0, 0, OffsetLayout::EvenQ),
+                offset!(0, -1, OffsetLayout::EvenQ),
+                offset!(1, -1, OffsetLayout::EvenQ),
+                offset!(2, -1, OffsetLayout::EvenQ),
+            ]
+        );
+
+        // Neighbors agree with axial for every layout.
+        for layout in LAYOUTS {
+            for coord in axial!(0, 0).range(3) {
+                assert_eq!(
+                    coord.to_offset(layout).neighbors().map(|n| n.to_axial()),
+                    coord.neighbors()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(
+            offset!(0, 0, OffsetLayout::OddR).distance(offset!(3, 3, OffsetLayout::OddR)),
+            5
+        );
+        assert_eq!(
+            offset!(1, 3, OffsetLayout::EvenR).distance(offset!(0, 3, OffsetLayout::OddR)),
+            0
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!("{}", offset!(3, -4, OffsetLayout::EvenQ)),
+            "Offset(3, -4, EvenQ)"
+        );
+        for layout in LAYOUTS {
+            let coord = offset!(2, 5, layout);
+            assert_eq!(format!("{layout}").parse(), Ok(layout));
+            assert_eq!(format!("{coord}").parse(), Ok(coord));
+        }
+        assert_eq!(
+            "Offset(3, 4, OddS)".parse::<Offset>(),
+            Err(ParseCoordError::Component)
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Superhex chunks, hexagons of hexes tiling a hex grid.
+
+use crate::lib::*;
+
+use super::{axial, Axial, AxialRange};
+
+/// Groups hexes into superhexes, hexagonal chunks of a fixed radius.
+///
+/// Superhexes tile the grid exactly, every hex belongs to a single chunk. Chunks are addressed by
+/// an [`Axial`] coordinate in the superhex lattice, chunk `(1, 0)` is the neighbor of chunk
+/// `(0, 0)` in the [`Front`](super::HexDirection::Front) direction and so on. Since chunk
+/// coordinates are regular axial coordinates, chunk-level algorithms can use the hex API as is,
+/// e.g. [`Axial::neighbors`] and [`Axial::distance`] give the adjacent chunks and the number of
+/// chunks between two chunks.
+///
+/// Within a chunk a hex is identified by its local offset from the chunk's center, which is
+/// within `radius` of `(0, 0)`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct SuperhexLayout {
+    /// Radius of each superhex, a radius of 0 makes every hex its own chunk.
+    pub radius: u32,
+}
+
+impl SuperhexLayout {
+    /// Constructor for a superhex layout.
+    pub const fn new(radius: u32) -> Self {
+        Self { radius }
+    }
+
+    /// Number of hexes in each superhex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::SuperhexLayout;
+    ///
+    /// assert_eq!(SuperhexLayout::new(1).area(), 7);
+    /// ```
+    pub fn area(&self) -> usize {
+        let radius = self.radius as usize;
+        3 * radius * (radius + 1) + 1
+    }
+
+    // Hex offsets between the centers of the chunks (0, 0) and (1, 0), (0, 1) respectively.
+    fn basis(&self) -> (Axial, Axial) {
+        let radius = self.radius as i32;
+        (axial!(2 * radius + 1, -radius), axial!(radius, radius + 1))
+    }
+
+    /// Get the hex at the center of a chunk.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, SuperhexLayout, axial};
+    ///
+    /// assert_eq!(SuperhexLayout::new(1).center(axial!(1, 0)), axial!(3, -1));
+    /// ```
+    pub fn center(&self, chunk: Axial) -> Axial {
+        let (q_basis, r_basis) = self.basis();
+        q_basis * chunk.q + r_basis * chunk.r
+    }
+
+    /// Get the chunk containing a hex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, SuperhexLayout, axial};
+    ///
+    /// let layout = SuperhexLayout::new(1);
+    /// assert_eq!(layout.chunk(axial!(1, 0)), axial!(0, 0));
+    /// assert_eq!(layout.chunk(axial!(2, 0)), axial!(1, 0));
+    /// ```
+    pub fn chunk(&self, coord: Axial) -> Axial {
+        let radius = self.radius as i32;
+        let area = self.area() as f64;
+
+        // Solve coord = q * q_basis + r * r_basis in fractional chunk space.
+        let (q, r) = (coord.q as f64, coord.r as f64);
+        let estimate = Axial::round((
+            ((radius + 1) as f64 * q - radius as f64 * r) / area,
+            (radius as f64 * q + (2 * radius + 1) as f64 * r) / area,
+        ));
+
+        // Rounding lands on the right chunk or one adjacent to it, check which contains the hex.
+        [estimate]
+            .into_iter()
+            .chain(estimate.neighbors())
+            .find(|chunk| coord.distance(self.center(*chunk)) <= radius)
+            .unwrap_or(estimate)
+    }
+
+    /// Convert a hex to its (chunk, local) pair.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, SuperhexLayout, axial};
+    ///
+    /// let layout = SuperhexLayout::new(1);
+    /// assert_eq!(layout.to_chunk(axial!(4, -1)), (axial!(1, 0), axial!(1, 0)));
+    /// ```
+    pub fn to_chunk(&self, coord: Axial) -> (Axial, Axial) {
+        let chunk = self.chunk(coord);
+        (chunk, coord - self.center(chunk))
+    }
+
+    /// Convert a (chunk, local) pair to the hex it identifies.
+    ///
+    /// Returns [`None`] if local lies outside the superhex's radius.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, SuperhexLayout, axial};
+    ///
+    /// let layout = SuperhexLayout::new(1);
+    /// assert_eq!(layout.from_chunk(axial!(1, 0), axial!(1, 0)), Some(axial!(4, -1)));
+    /// assert_eq!(layout.from_chunk(axial!(1, 0), axial!(2, 0)), None);
+    /// ```
+    pub fn from_chunk(&self, chunk: Axial, local: Axial) -> Option<Axial> {
+        match local.distance(axial!(0, 0)) as u32 <= self.radius {
+            true => Some(self.center(chunk) + local),
+            false => None,
+        }
+    }
+
+    /// Lazily get every hex within a chunk.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, SuperhexLayout, axial};
+    ///
+    /// let layout = SuperhexLayout::new(2);
+    /// assert!(layout.hexes(axial!(-1, 3)).all(|coord| layout.chunk(coord) == axial!(-1, 3)));
+    /// ```
+    pub fn hexes(&self, chunk: Axial) -> AxialRange {
+        self.center(chunk).range_iter(self.radius as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area() {
+        assert_eq!(SuperhexLayout::new(0).area(), 1);
+        assert_eq!(SuperhexLayout::new(2).area(), 19);
+        for radius in 0..5 {
+            let layout = SuperhexLayout::new(radius);
+            assert_eq!(layout.hexes(axial!(2, 1)).len(), layout.area());
+        }
+    }
+
+    #[test]
+    fn center() {
+        let layout = SuperhexLayout::new(2);
+        assert_eq!(layout.center(axial!(0, 0)), axial!(0, 0));
+        assert_eq!(layout.center(axial!(1, 0)), axial!(5, -2));
+        assert_eq!(layout.center(axial!(0, 1)), axial!(2, 3));
+        assert_eq!(layout.center(axial!(-1, 1)), axial!(-3, 5));
+
+        // Rotating a chunk rotates its center.
+        for rot in 0..6 {
+            assert_eq!(
+                layout.center(axial!(1, 0).rotate(None, rot)),
+                layout.center(axial!(1, 0)).rotate(None, rot)
+            );
+        }
+    }
+
+    #[test]
+    fn chunk() {
+        assert_eq!(SuperhexLayout::new(0).chunk(axial!(3, -7)), axial!(3, -7));
+
+        for radius in 0..5 {
+            let layout = SuperhexLayout::new(radius);
+            for coord in axial!(0, 0).range_iter(15) {
+                let (chunk, local) = layout.to_chunk(coord);
+                assert!(local.distance(axial!(0, 0)) <= radius as i32);
+                assert_eq!(layout.from_chunk(chunk, local), Some(coord));
+            }
+        }
+    }
+
+    #[test]
+    fn no_gaps_or_overlaps() {
+        let layout = SuperhexLayout::new(3);
+        for chunk in axial!(0, 0).range_iter(2) {
+            for coord in layout.hexes(chunk) {
+                assert_eq!(layout.to_chunk(coord).0, chunk);
+            }
+
+            // Adjacent chunks share a border.
+            for neighbor in chunk.neighbors() {
+                assert!(layout.hexes(chunk).any(|coord| {
+                    coord
+                        .neighbors()
+                        .iter()
+                        .any(|adj| layout.chunk(*adj) == neighbor)
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn from_chunk() {
+        let layout = SuperhexLayout::new(2);
+        assert_eq!(
+            layout.from_chunk(axial!(0, 1), axial!(-2, 2)),
+            Some(axial!(0, 5))
+        );
+        assert_eq!(layout.from_chunk(axial!(0, 1), axial!(-3, 0)), None);
+    }
+}
@@ -0,0 +1,247 @@
+//! Wrap-around topologies for hex based grids.
+
+use crate::core::topology::Topology;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::lib::*;
+
+use super::{axial, Axial};
+
+impl Axial {
+    // Move the coordinate by a number of map widths and heights.
+    fn translate_periods(&self, topology: Topology, (cols, rows): (i32, i32)) -> Self {
+        let (width, height) = topology.periods();
+        let width = width.unwrap_or_default();
+        let height = height.unwrap_or_default();
+
+        // An even number of OddR rows keeps the column offset, only q needs to be corrected.
+        *self + axial!(cols * width - rows * height / 2, rows * height)
+    }
+
+    /// Canonicalise the coordinate to its position within the map.
+    ///
+    /// Wrapped coordinates lie within `0..width` columns and `0..height` rows of the
+    /// [`OddR`](super::OffsetLayout::OddR) offset layout. On an unbounded axis the coordinate is
+    /// left as is.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let topology = Topology::cylinder(4).unwrap();
+    /// assert_eq!(axial!(4, 0).wrap(topology), axial!(0, 0));
+    /// assert_eq!(axial!(-1, 0).wrap(topology), axial!(3, 0));
+    /// ```
+    pub fn wrap(&self, topology: Topology) -> Self {
+        let (width, height) = topology.periods();
+        let rows = height.map_or(0, |height| self.r.div_euclid(height));
+        let coord = self.translate_periods(topology, (0, -rows));
+
+        let col = coord.q + (coord.r - (coord.r & 1)) / 2;
+        let cols = width.map_or(0, |width| col.div_euclid(width));
+        coord.translate_periods(topology, (-cols, 0))
+    }
+
+    // The copy of b, out of the copies produced by wrapping, nearest to the wrapped self.
+    fn nearest_image(&self, b: Self, topology: Topology) -> Self {
+        let a = self.wrap(topology);
+        let b = b.wrap(topology);
+
+        topology
+            .images()
+            .map(|periods| b.translate_periods(topology, periods))
+            .min_by_key(|image| a.distance(*image))
+            .unwrap_or(b)
+    }
+
+    /// Get the wrapped neighbors of this coordinate.
+    ///
+    /// Neighbors across a seam are wrapped to the other side of the map.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let neighbors = axial!(3, 0).wrapped_neighbors(Topology::cylinder(4).unwrap());
+    /// assert!(neighbors.contains(&axial!(0, 0)));
+    /// ```
+    pub fn wrapped_neighbors(&self, topology: Topology) -> [Self; 6] {
+        self.wrap(topology)
+            .neighbors()
+            .map(|coord| coord.wrap(topology))
+    }
+
+    /// Compute the distance between two coordinates, taking the shortest route across seams.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// assert_eq!(axial!(0, 0).wrapped_distance(axial!(3, 0), Topology::cylinder(4).unwrap()), 1);
+    /// ```
+    pub fn wrapped_distance(&self, b: Self, topology: Topology) -> i32 {
+        self.wrap(topology)
+            .distance(self.nearest_image(b, topology))
+    }
+
+    /// Calculate the wrapped coordinates forming the shortest line between two points.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// let coords = axial!(0, 0).wrapped_line(axial!(3, 0), Topology::cylinder(4).unwrap());
+    /// assert_eq!(coords, vec![axial!(0, 0), axial!(3, 0)]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn wrapped_line(&self, b: Self, topology: Topology) -> Vec<Self> {
+        self.wrap(topology)
+            .line_iter(self.nearest_image(b, topology))
+            .map(|coord| coord.wrap(topology))
+            .collect()
+    }
+
+    /// Calculate all the wrapped coordinates within a range.
+    ///
+    /// Each coordinate appears once, even if the range overlaps itself across a seam.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::hex::coordinate::{Axial, axial};
+    ///
+    /// // A map 2 columns wide, both in-row neighbors are the same tile.
+    /// let coords = axial!(0, 0).wrapped_range(1, Topology::cylinder(2).unwrap());
+    /// assert_eq!(coords.len(), 6);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn wrapped_range(&self, range: i32, topology: Topology) -> Vec<Self> {
+        let mut seen = BTreeSet::new();
+
+        self.wrap(topology)
+            .range_iter(range)
+            .map(|coord| coord.wrap(topology))
+            .filter(|coord| seen.insert((coord.q, coord.r)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cylinder() -> Topology {
+        Topology::cylinder(4).unwrap()
+    }
+
+    fn torus() -> Topology {
+        Topology::torus(4, 4).unwrap()
+    }
+
+    #[test]
+    fn wrap() {
+        assert_eq!(axial!(5, -3).wrap(Topology::plane()), axial!(5, -3));
+
+        assert_eq!(axial!(4, 0).wrap(cylinder()), axial!(0, 0));
+        assert_eq!(axial!(-1, 0).wrap(cylinder()), axial!(3, 0));
+        // OddR column 0 on row 3 is q = -1
+        assert_eq!(axial!(3, 3).wrap(cylinder()), axial!(-1, 3));
+        assert_eq!(axial!(0, 100).wrap(cylinder()), axial!(-48, 100));
+
+        assert_eq!(axial!(0, 4).wrap(torus()), axial!(2, 0));
+        assert_eq!(axial!(0, -1).wrap(torus()), axial!(2, 3));
+
+        // Every canonical coordinate is its own canonical form.
+        for coord in axial!(0, 0).range_iter(6) {
+            let wrapped = coord.wrap(torus());
+            assert_eq!(wrapped.wrap(torus()), wrapped);
+            assert!((0..4).contains(&wrapped.r));
+            assert!((0..4).contains(&(wrapped.q + (wrapped.r - (wrapped.r & 1)) / 2)));
+        }
+    }
+
+    #[test]
+    fn wrapped_neighbors() {
+        assert_eq!(
+            axial!(1, 1).wrapped_neighbors(Topology::plane()),
+            axial!(1, 1).neighbors()
+        );
+
+        let neighbors = axial!(3, 0).wrapped_neighbors(cylinder());
+        assert!(neighbors.contains(&axial!(0, 0)));
+        assert!(neighbors.contains(&axial!(0, -1).wrap(cylinder())));
+
+        let neighbors = axial!(0, 0).wrapped_neighbors(torus());
+        assert!(neighbors.contains(&axial!(2, 3)));
+        for neighbor in neighbors {
+            assert_eq!(neighbor.wrapped_distance(axial!(0, 0), torus()), 1);
+        }
+    }
+
+    #[test]
+    fn wrapped_distance() {
+        assert_eq!(
+            axial!(0, 0).wrapped_distance(axial!(3, 0), Topology::plane()),
+            3
+        );
+        assert_eq!(axial!(0, 0).wrapped_distance(axial!(3, 0), cylinder()), 1);
+        assert_eq!(axial!(0, 0).wrapped_distance(axial!(7, 0), cylinder()), 1);
+        assert_eq!(axial!(0, 0).wrapped_distance(axial!(-2, 3), torus()), 1);
+
+        // Symmetric and never longer than the plane distance.
+        for a in axial!(0, 0).range_iter(3) {
+            for b in axial!(1, 1).range_iter(3) {
+                let dist = a.wrapped_distance(b, torus());
+                assert_eq!(dist, b.wrapped_distance(a, torus()));
+                assert!(dist <= a.distance(b));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn wrapped_line() {
+        assert_eq!(
+            axial!(0, 0).wrapped_line(axial!(2, 0), Topology::plane()),
+            axial!(0, 0).line(axial!(2, 0))
+        );
+        assert_eq!(
+            axial!(0, 0).wrapped_line(axial!(2, 0), Topology::cylinder(5).unwrap()),
+            vec![axial!(0, 0), axial!(1, 0), axial!(2, 0)]
+        );
+        assert_eq!(
+            axial!(0, 0).wrapped_line(axial!(2, 0), Topology::cylinder(3).unwrap()),
+            vec![axial!(0, 0), axial!(2, 0)]
+        );
+
+        let line = axial!(0, 0).wrapped_line(axial!(1, 2), torus());
+        assert_eq!(
+            line.len() as i32,
+            axial!(0, 0).wrapped_distance(axial!(1, 2), torus()) + 1
+        );
+        assert!(line.iter().all(|coord| coord.wrap(torus()) == *coord));
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn wrapped_range() {
+        assert_eq!(
+            axial!(0, 0).wrapped_range(2, Topology::plane()),
+            axial!(0, 0).range(2)
+        );
+
+        // Wide enough that nothing overlaps.
+        assert_eq!(
+            axial!(0, 0)
+                .wrapped_range(1, Topology::cylinder(3).unwrap())
+                .len(),
+            7
+        );
+
+        // The whole map is within range.
+        assert_eq!(axial!(1, 1).wrapped_range(4, torus()).len(), 16);
+    }
+}
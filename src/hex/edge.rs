@@ -1,10 +1,13 @@
 //! Handles edges in a hexagonal grid.
 
+use crate::core::coordinate::{Distance, Line, Neighbors, Range};
+use crate::core::parse::{self, ParseCoordError};
+use crate::core::transform::Transform;
 use crate::lib::*;
 
 use super::{
-    coordinate::{axial, Axial, HexDirection},
-    vertex::{Vertex, VertexDirection},
+    coordinate::{axial, Axes, Axial, HexDirection},
+    vertex::{Vertex, VertexDirection, VertexSpin},
 };
 
 /// Orientation of an edge.
@@ -19,6 +22,30 @@ pub enum EdgeDirection {
     NorthEast,
 }
 
+impl Display for EdgeDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EdgeDirection::West => "West",
+            EdgeDirection::NorthWest => "NorthWest",
+            EdgeDirection::NorthEast => "NorthEast",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for EdgeDirection {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "West" => Ok(EdgeDirection::West),
+            "NorthWest" => Ok(EdgeDirection::NorthWest),
+            "NorthEast" => Ok(EdgeDirection::NorthEast),
+            _ => Err(ParseCoordError::Component),
+        }
+    }
+}
+
 /// A hexagonal edge.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
@@ -67,7 +94,70 @@ impl From<HexDirection> for Edge {
     }
 }
 
+impl Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Edge({}, {}, {})", self.q, self.r, self.dir)
+    }
+}
+
+/// Parses the form produced by [`Display`], e.g. `Edge(1, 0, West)`.
+impl FromStr for Edge {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [q, r, dir] = parse::components(s, "Edge")?;
+        Ok(edge!(
+            parse::component(q)?,
+            parse::component(r)?,
+            parse::component(dir)?
+        ))
+    }
+}
+
 impl Edge {
+    /// Get the edge shared by two neighboring hexes.
+    ///
+    /// Produces [`None`] if the hexes are not neighbors.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// let edge = Edge::between(axial!(0, 0), axial!(0, 1));
+    /// assert_eq!(edge, Some(edge!(0, 1, EdgeDirection::NorthWest)));
+    /// assert_eq!(Edge::between(axial!(0, 0), axial!(2, 0)), None);
+    /// ```
+    pub fn between(a: Axial, b: Axial) -> Option<Self> {
+        HexDirection::iter()
+            .find(|dir| a.neighbor(*dir) == b)
+            .map(|dir| a.edge(dir))
+    }
+
+    /// Get the edge joining two adjacent vertices.
+    ///
+    /// Produces [`None`] if the vertices are not adjacent, or either is not a vertex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let a = axial!(0, 0).vertex(VertexDirection::UpLeft);
+    /// let b = axial!(0, 0).vertex(VertexDirection::Up);
+    /// assert_eq!(Edge::from_endpoints(a, b), Some(edge!(0, 0, EdgeDirection::NorthWest)));
+    /// ```
+    pub fn from_endpoints(a: Vertex, b: Vertex) -> Option<Self> {
+        if a == b {
+            return None;
+        }
+
+        a.adjacent_edges()?
+            .into_iter()
+            .find(|edge| edge.endpoints().contains(&b))
+    }
+
     /// Get the adjacent hexes that share this edge.
     ///
     /// The first coordinate in the array will always be the (q, r) coordinate.
@@ -145,11 +235,346 @@ impl Edge {
             ],
         }
     }
+
+    /// Compute the number of steps between two edges, stepping to an adjacent edge each time.
+    ///
+    /// See [`Edge::adjacent_edges`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// let edge = edge!(0, 0, EdgeDirection::West);
+    /// assert_eq!(edge.distance(edge), 0);
+    /// assert_eq!(edge.distance(edge!(0, 0, EdgeDirection::NorthWest)), 1);
+    /// assert_eq!(edge.distance(edge!(1, 0, EdgeDirection::West)), 3);
+    /// ```
+    pub fn distance(&self, b: Self) -> u32 {
+        if *self == b {
+            return 0;
+        }
+
+        // Walk the shortest path between the nearest endpoints, then onto b.
+        let [a0, a1] = self.endpoints();
+        let [b0, b1] = b.endpoints();
+        1 + [
+            a0.distance(b0),
+            a0.distance(b1),
+            a1.distance(b0),
+            a1.distance(b1),
+        ]
+        .into_iter()
+        .min()
+        .unwrap_or_default()
+    }
+
+    /// Lazily produce the edges within a number of steps of this edge.
+    ///
+    /// See [`Edge::distance`]. Does not allocate, see [`Edge::range`] for a collected version.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// // self and its 4 adjacent edges
+    /// assert_eq!(edge!(0, 0, EdgeDirection::West).range_iter(1).count(), 5);
+    /// ```
+    pub fn range_iter(self, dist: i32) -> EdgeRange {
+        EdgeRange::new(self, dist)
+    }
+
+    /// Produce the edges within a number of steps of this edge.
+    ///
+    /// See [`Edge::range_iter`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn range(self, dist: i32) -> Vec<Self> {
+        self.range_iter(dist).collect()
+    }
+
+    /// Produce the edges exactly a number of steps from this edge.
+    ///
+    /// Edges are grouped by their endpoint nearest this edge, in the order of
+    /// [`Vertex::ring`]. A distance of 0 produces self.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// let edge = edge!(0, 0, EdgeDirection::West);
+    /// assert_eq!(edge.ring(1).len(), 4);
+    /// assert_eq!(edge.ring(2).len(), 8);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn ring(self, dist: i32) -> Vec<Self> {
+        let mut ret = Vec::new();
+        if dist <= 0 {
+            if dist == 0 {
+                ret.push(self);
+            }
+            return ret;
+        }
+
+        // An edge is one step further than its nearest endpoint, so the ring is made of the
+        // edges leaving the vertices one step closer without coming back towards self.
+        let [a0, a1] = self.endpoints();
+        let nearest = |vertex: Vertex| vertex.distance(a0).min(vertex.distance(a1));
+        let inner = dist as u32 - 1;
+
+        // Vertices around a1 that are as close to a0 were already found around a0.
+        let near_a0 = a0.ring(dist - 1).into_iter();
+        let near_a1 = a1.ring(dist - 1).into_iter();
+        let vertices = near_a0
+            .filter(|&vertex| vertex.distance(a1) >= inner)
+            .chain(near_a1.filter(|&vertex| vertex.distance(a0) > inner));
+
+        for vertex in vertices {
+            let up = matches!(vertex.try_to_axial(), Some((_, VertexSpin::Up)));
+            for edge in vertex.adjacent_edges().into_iter().flatten() {
+                let [b0, b1] = edge.endpoints();
+                let other = if b0 == vertex { b1 } else { b0 };
+
+                // Edges between two vertices of the inner ring are found from both ends, only
+                // keep them from their Up end.
+                let keep = match nearest(other).cmp(&inner) {
+                    Ordering::Less => false,
+                    Ordering::Equal => up && edge != self,
+                    Ordering::Greater => true,
+                };
+                if keep {
+                    ret.push(edge);
+                }
+            }
+        }
+        ret
+    }
+
+    /// Applies a transform matrix to this edge.
+    ///
+    /// Scale has no meaning with an edge so we do not scale here.
+    ///
+    /// The order of applications is rotation then translation, see [`Axial::apply_transform`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::transform::{Transform, transform, Vector2D, vector2d};
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// let edge = edge!(0, 0, EdgeDirection::West);
+    /// let transformed = edge.apply_transform(transform!(axial!(2, 0), 3));
+    /// assert_eq!(transformed, edge!(3, 0, EdgeDirection::West));
+    /// ```
+    pub fn apply_transform(&self, transform: Transform<Axial>) -> Self {
+        let rotated = self.rotate(None, transform.rotation);
+        edge!(
+            rotated.q + transform.translation.q,
+            rotated.r + transform.translation.r,
+            rotated.dir
+        )
+    }
+
+    /// Rotate an edge.
+    ///
+    /// `center` Optionally can specify a hex to rotate about. None will rotate about (0, 0).
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// The rotated edge is named from whichever of its hexes keeps the direction one of
+    /// [`EdgeDirection`]'s.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// // The west edge of (0, 0) rotates onto its north west edge.
+    /// let edge = edge!(0, 0, EdgeDirection::West).rotate(None, 1);
+    /// assert_eq!(edge, edge!(0, 0, EdgeDirection::NorthWest));
+    ///
+    /// // Its north east edge rotates onto the west edge of (1, 0).
+    /// let edge = edge!(0, 0, EdgeDirection::NorthEast).rotate(None, 1);
+    /// assert_eq!(edge, edge!(1, 0, EdgeDirection::West));
+    /// ```
+    pub fn rotate(&self, center: Option<Axial>, rot_dir: i32) -> Self {
+        self.transform_about_hex(|hex| hex.rotate(center, rot_dir), |dir| dir + rot_dir)
+    }
+
+    /// Reflect an edge.
+    ///
+    /// `center` Optionally can specify a hex to reflect about. None will reflect about (0, 0).
+    ///
+    /// See [`Axial::reflect`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::Axes;
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// let edge = edge!(0, 0, EdgeDirection::NorthWest).reflect(None, Axes::R);
+    /// assert_eq!(edge, edge!(0, 0, EdgeDirection::NorthEast));
+    /// ```
+    pub fn reflect(&self, center: Option<Axial>, axes: Axes) -> Self {
+        self.mirror(center, axes.into())
+    }
+
+    // Transform the edge as its hex and the direction from it to its other hex, which avoids the
+    // symmetry lattice for transforms about a hex.
+    //
+    // `dir` maps the `i32` conversion of a `HexDirection`.
+    pub(crate) fn transform_about_hex(
+        &self,
+        hex: impl Fn(Axial) -> Axial,
+        dir: impl Fn(i32) -> i32,
+    ) -> Self {
+        // The inverse of `From<HexDirection>`.
+        let other = match self.dir {
+            EdgeDirection::West => HexDirection::Back,
+            EdgeDirection::NorthWest => HexDirection::BackLeft,
+            EdgeDirection::NorthEast => HexDirection::FrontLeft,
+        };
+        hex(axial!(self.q, self.r)).edge(HexDirection::from(dir(other.into())))
+    }
+}
+
+impl Neighbors for Edge {
+    type NeighborIter = array::IntoIter<Self, 4>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        self.adjacent_edges().into_iter()
+    }
+}
+
+impl Distance for Edge {
+    fn distance(&self, b: Self) -> u32 {
+        Edge::distance(self, b)
+    }
+}
+
+impl Range for Edge {
+    type RangeIter = EdgeRange;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        Edge::range_iter(*self, range)
+    }
 }
 
+/// The edges along the shortest path of vertices between the nearest endpoints of two edges.
+impl Line for Edge {
+    type LineIter = EdgeLine;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        EdgeLine::new(*self, b)
+    }
+}
+
+/// Iterator over every edge within a number of steps, see [`Edge::range_iter`].
+#[derive(Clone, Debug)]
+pub struct EdgeRange {
+    center: Edge,
+    dist: i32,
+    vertices: <Vertex as Range>::RangeIter,
+    edges: iter::Flatten<option::IntoIter<[Edge; 3]>>,
+}
+
+impl EdgeRange {
+    fn new(center: Edge, dist: i32) -> Self {
+        // Every edge has exactly one endpoint of each spin, so visiting the edges of the Up
+        // vertices nearby produces each edge once. The far endpoint of an edge in range is at
+        // most one step further than the near one.
+        let [start, _] = center.endpoints();
+        let vertices = match dist {
+            0.. => start.range_iter(dist.saturating_add(1)),
+            _ => start.range_iter(-1),
+        };
+
+        Self {
+            center,
+            dist,
+            vertices,
+            edges: None.into_iter().flatten(),
+        }
+    }
+}
+
+impl Iterator for EdgeRange {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for edge in self.edges.by_ref() {
+                if edge.distance(self.center) <= self.dist as u32 {
+                    return Some(edge);
+                }
+            }
+
+            let vertex = self.vertices.next()?;
+            if let Some((_, VertexSpin::Up)) = vertex.try_to_axial() {
+                self.edges = vertex.adjacent_edges().into_iter().flatten();
+            }
+        }
+    }
+}
+
+impl FusedIterator for EdgeRange {}
+
+/// Iterator over the edges forming a line, see [`Line::line_iter`].
+#[derive(Clone, Debug)]
+pub struct EdgeLine {
+    start: Option<Edge>,
+    vertices: <Vertex as Line>::LineIter,
+    prev: Option<Vertex>,
+    end: Option<Edge>,
+}
+
+impl EdgeLine {
+    fn new(a: Edge, b: Edge) -> Self {
+        // The pair of endpoints the distance between the edges is measured through.
+        let [a0, a1] = a.endpoints();
+        let [b0, b1] = b.endpoints();
+        let (from, to) = [(a0, b0), (a0, b1), (a1, b0), (a1, b1)]
+            .into_iter()
+            .min_by_key(|(from, to)| from.distance(*to))
+            .unwrap_or((a0, b0));
+
+        // A line to itself is only the edge, the path of a single vertex has no edges.
+        let (vertices, end) = match a == b {
+            true => (from.line_iter(from), None),
+            false => (from.line_iter(to), Some(b)),
+        };
+
+        Self {
+            start: Some(a),
+            vertices,
+            prev: None,
+            end,
+        }
+    }
+}
+
+impl Iterator for EdgeLine {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(start) = self.start.take() {
+            return Some(start);
+        }
+
+        // Consecutive vertices of the path are joined by an edge. The path starts and ends on an
+        // endpoint of each edge, so its edges never repeat either.
+        for vertex in self.vertices.by_ref() {
+            if let Some(prev) = self.prev.replace(vertex) {
+                return Edge::from_endpoints(prev, vertex);
+            }
+        }
+        self.end.take()
+    }
+}
+
+impl FusedIterator for EdgeLine {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hex::testing::{assert_follows_hexes, sorted};
 
     #[test]
     fn default() {
@@ -274,4 +699,129 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            format!("{}", edge!(1, 0, EdgeDirection::West)),
+            "Edge(1, 0, West)"
+        );
+        for dir in [
+            EdgeDirection::West,
+            EdgeDirection::NorthWest,
+            EdgeDirection::NorthEast,
+        ] {
+            let edge = edge!(-3, 2, dir);
+            assert_eq!(format!("{edge}").parse(), Ok(edge));
+        }
+        assert_eq!(
+            "Edge(1, 0, East)".parse::<Edge>(),
+            Err(ParseCoordError::Component)
+        );
+    }
+
+    #[test]
+    fn between() {
+        for coord in axial!(1, -1).range_iter(2) {
+            for dir in HexDirection::iter() {
+                let neighbor = coord.neighbor(dir);
+                let edge = coord.edge(dir);
+                assert_eq!(Edge::between(coord, neighbor), Some(edge));
+                assert_eq!(Edge::between(neighbor, coord), Some(edge));
+                assert_eq!(sorted(edge.adjacent_hexes()), sorted([coord, neighbor]));
+            }
+            assert_eq!(Edge::between(coord, coord), None);
+            assert_eq!(Edge::between(coord, coord + axial!(1, 1)), None);
+        }
+    }
+
+    #[test]
+    fn from_endpoints() {
+        for coord in axial!(0, 2).range_iter(2) {
+            for edge in coord.edges() {
+                let [a, b] = edge.endpoints();
+                assert_eq!(Edge::from_endpoints(a, b), Some(edge));
+                assert_eq!(Edge::from_endpoints(b, a), Some(edge));
+                assert_eq!(Edge::from_endpoints(a, a), None);
+            }
+        }
+
+        let up = axial!(0, 0).vertex(VertexDirection::Up);
+        let down = axial!(0, 0).vertex(VertexDirection::Down);
+        assert_eq!(Edge::from_endpoints(up, down), None);
+        assert_eq!(Edge::from_endpoints(Vertex::new(0, 0, 0), up), None);
+    }
+
+    #[test]
+    fn transform() {
+        use crate::{
+            core::transform::{vector2d, Vector2D},
+            transform,
+        };
+
+        let center = axial!(1, -2);
+        for coord in axial!(0, 0).range_iter(2) {
+            for edge in coord.edges() {
+                for rot_dir in -6..=6 {
+                    let rotated = edge.rotate(Some(center), rot_dir);
+                    assert_follows_hexes(edge, rotated, |hex| hex.rotate(Some(center), rot_dir));
+
+                    let transform = transform!(axial!(-3, 1), rot_dir);
+                    assert_follows_hexes(edge, edge.apply_transform(transform), |hex| {
+                        hex.apply_transform(transform)
+                    });
+                }
+
+                for axes in [Axes::Q, Axes::R, Axes::S] {
+                    let reflected = edge.reflect(Some(center), axes);
+                    assert_eq!(reflected.reflect(Some(center), axes), edge);
+                    assert_follows_hexes(edge, reflected, |hex| hex.reflect(Some(center), axes));
+                }
+            }
+        }
+
+        // Transforms about a hex are not limited by the symmetry lattice.
+        let far = axial!(i32::MAX / 4, 0);
+        let edge = far.edge(HexDirection::Back);
+        assert_eq!(edge.rotate(Some(far), 1), far.edge(HexDirection::BackLeft));
+        assert_eq!(
+            edge.reflect(Some(far), Axes::R),
+            far.edge(HexDirection::Front)
+        );
+        let edge = axial!(i32::MAX - 1, 0).edge(HexDirection::Back);
+        assert_follows_hexes(edge, edge.rotate(None, 3), |hex| hex.rotate(None, 3));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn range_ring() {
+        let edge = edge!(0, 0, EdgeDirection::NorthWest);
+        assert_eq!(edge.range(0), [edge]);
+        assert!(edge.range(-1).is_empty());
+
+        let mut ring = edge.ring(1);
+        let mut adjacent = edge.adjacent_edges().to_vec();
+        ring.sort_by_key(|edge| (edge.q, edge.r, edge.dir as i32));
+        adjacent.sort_by_key(|edge| (edge.q, edge.r, edge.dir as i32));
+        assert_eq!(ring, adjacent);
+
+        // Rings hold the edges of the range at exactly their distance, each once.
+        for edge in [
+            edge!(1, -2, EdgeDirection::West),
+            edge!(0, 3, EdgeDirection::NorthWest),
+            edge!(-1, 0, EdgeDirection::NorthEast),
+        ] {
+            for dist in 0..5 {
+                let mut ring = edge.ring(dist);
+                let mut expected: Vec<Edge> = edge
+                    .range_iter(dist)
+                    .filter(|other| other.distance(edge) == dist as u32)
+                    .collect();
+                ring.sort_by_key(|edge| (edge.q, edge.r, edge.dir as i32));
+                expected.sort_by_key(|edge| (edge.q, edge.r, edge.dir as i32));
+                assert_eq!(ring, expected);
+            }
+            assert!(edge.ring(-1).is_empty());
+        }
+    }
 }
@@ -2,7 +2,10 @@
 
 use crate::lib::*;
 
-use super::coordinate::{axial, Axial};
+use super::{
+    coordinate::{Axial, FracAxial, Offset, OffsetLayout, OffsetParity},
+    vertex::Vertex,
+};
 
 /// Enum denoting orientation of hexagons in a grid.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -15,6 +18,38 @@ pub enum HexOrientation {
     PointyTop,
 }
 
+impl HexOrientation {
+    /// The [`OffsetLayout`] whose shoved lines align with this orientation.
+    ///
+    /// Pointy top hexagons shove rows and flat top hexagons shove columns.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{OffsetLayout, OffsetParity};
+    /// use gridava::hex::grid::HexOrientation;
+    ///
+    /// let layout = HexOrientation::FlatTop.offset_layout(OffsetParity::Even);
+    /// assert_eq!(layout, OffsetLayout::EvenQ);
+    /// ```
+    pub fn offset_layout(&self, parity: OffsetParity) -> OffsetLayout {
+        match (self, parity) {
+            (HexOrientation::PointyTop, OffsetParity::Odd) => OffsetLayout::OddR,
+            (HexOrientation::PointyTop, OffsetParity::Even) => OffsetLayout::EvenR,
+            (HexOrientation::FlatTop, OffsetParity::Odd) => OffsetLayout::OddQ,
+            (HexOrientation::FlatTop, OffsetParity::Even) => OffsetLayout::EvenQ,
+        }
+    }
+}
+
+impl From<OffsetLayout> for HexOrientation {
+    fn from(value: OffsetLayout) -> Self {
+        match value {
+            OffsetLayout::OddR | OffsetLayout::EvenR => HexOrientation::PointyTop,
+            OffsetLayout::OddQ | OffsetLayout::EvenQ => HexOrientation::FlatTop,
+        }
+    }
+}
+
 /// A helper converter struct that will help facilitate conversion to and from the grid and world space.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Default)]
@@ -105,6 +140,122 @@ impl WSConverter {
             }
         }
     }
+
+    /// Convert from world space to fractional hex coordinates.
+    ///
+    /// Unlike [`WSConverter::world_to_hex`] the position is not snapped to a tile.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::grid::{WSConverter, HexOrientation};
+    ///
+    /// let converter = WSConverter { size: 32.0, orientation: HexOrientation::PointyTop };
+    /// let position = converter.world_to_frac((100.0, 432.0));
+    /// ```
+    pub fn world_to_frac(&self, ws_coord: (f64, f64)) -> FracAxial {
+        let size = self.size as f64;
+        match self.orientation {
+            HexOrientation::PointyTop => FracAxial::new(
+                (SQRT_3 / 3.0 * ws_coord.0 - 1.0 / 3.0 * ws_coord.1) / size,
+                (2.0 / 3.0 * ws_coord.1) / size,
+            ),
+            HexOrientation::FlatTop => FracAxial::new(
+                (2.0 / 3.0 * ws_coord.0) / size,
+                (-1.0 / 3.0 * ws_coord.0 + SQRT_3 / 3.0 * ws_coord.1) / size,
+            ),
+        }
+    }
+
+    /// Convert from fractional hex to world space coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::FracAxial;
+    /// use gridava::hex::grid::{WSConverter, HexOrientation};
+    ///
+    /// let converter = WSConverter { size: 32.0, orientation: HexOrientation::PointyTop };
+    /// let position = converter.frac_to_world(FracAxial::new(0.5, 1.25));
+    /// ```
+    pub fn frac_to_world(&self, coord: FracAxial) -> (f64, f64) {
+        let size = self.size as f64;
+        match self.orientation {
+            HexOrientation::PointyTop => (
+                size * (SQRT_3 * coord.q + SQRT_3 / 2.0 * coord.r),
+                size * (3.0 / 2.0 * coord.r),
+            ),
+            HexOrientation::FlatTop => (
+                size * (3.0 / 2.0 * coord.q),
+                size * (SQRT_3 / 2.0 * coord.q + SQRT_3 * coord.r),
+            ),
+        }
+    }
+
+    /// Convert from a vertex to world space coordinates.
+    ///
+    /// Takes in a vertex and outputs the world space coordinates of the corner it sits on. A hex
+    /// center, see [`Triangle::is_tri_face()`](crate::triangle::coordinate::Triangle::is_tri_face),
+    /// outputs the center of its tile.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::grid::{WSConverter, HexOrientation};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let converter = WSConverter { size: 32.0, orientation: HexOrientation::PointyTop };
+    /// let (x, y) = converter.vertex_to_world(axial!(0, 0).vertex(VertexDirection::Up));
+    /// assert!(x.abs() < 1e-9 && (y + 32.0).abs() < 1e-9);
+    /// ```
+    pub fn vertex_to_world(&self, vertex: Vertex) -> (f64, f64) {
+        // A vertex is the centroid of the hexes around it, thirds of a tile from each center.
+        let coord = vertex.into_inner();
+        self.frac_to_world(FracAxial::new(
+            (2 * coord.x - coord.y - coord.z) as f64 / 3.0,
+            (2 * coord.y - coord.x - coord.z) as f64 / 3.0,
+        ))
+    }
+
+    /// Convert from world space to offset coordinates.
+    ///
+    /// The layout of the produced coordinate matches the converter's orientation,
+    /// see [`HexOrientation::offset_layout`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Offset, offset, OffsetLayout, OffsetParity};
+    /// use gridava::hex::grid::{WSConverter, HexOrientation};
+    ///
+    /// let converter = WSConverter { size: 32.0, orientation: HexOrientation::PointyTop };
+    /// let nearest_tile = converter.world_to_offset((0.0, 0.0), OffsetParity::Odd);
+    /// assert_eq!(nearest_tile, offset!(0, 0, OffsetLayout::OddR));
+    /// ```
+    pub fn world_to_offset(&self, ws_coord: (f64, f64), parity: OffsetParity) -> Offset {
+        self.world_to_hex(ws_coord)
+            .to_offset(self.orientation.offset_layout(parity))
+    }
+
+    /// Convert from offset to world space coordinates.
+    ///
+    /// Takes in an offset coordinate and outputs the world space coordinates of the tile's center.
+    ///
+    /// Returns [`None`] if the coordinate's layout does not match the converter's orientation.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Offset, offset, OffsetLayout};
+    /// use gridava::hex::grid::{WSConverter, HexOrientation};
+    ///
+    /// let converter = WSConverter { size: 32.0, orientation: HexOrientation::FlatTop };
+    /// assert!(converter.offset_to_world(offset!(4, 2, OffsetLayout::OddQ)).is_some());
+    /// assert!(converter.offset_to_world(offset!(4, 2, OffsetLayout::OddR)).is_none());
+    /// ```
+    pub fn offset_to_world(&self, coord: Offset) -> Option<(f64, f64)> {
+        if HexOrientation::from(coord.layout) == self.orientation {
+            Some(self.hex_to_world(coord.to_axial()))
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -262,6 +413,141 @@ mod tests {
         assert_f64_tuples_near!(grid.hex_to_world(axial!(-15, 0)), (-225.0, SQRT_3 * -75.0));
     }
 
+    #[test]
+    fn frac_to_world() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let grid = WSConverter {
+                size: 10.0,
+                orientation,
+            };
+
+            // Agrees with the tile conversion on tile centers.
+            for coord in [axial!(0, 0), axial!(8, -12), axial!(-12, 8), axial!(15, 0)] {
+                assert_f64_tuples_near!(grid.frac_to_world(coord.into()), grid.hex_to_world(coord));
+            }
+        }
+    }
+
+    #[test]
+    fn world_to_frac() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let grid = WSConverter {
+                size: 10.0,
+                orientation,
+            };
+
+            for coord in [
+                FracAxial::new(0.0, 0.0),
+                FracAxial::new(0.5, -0.25),
+                FracAxial::new(-3.75, 2.5),
+                FracAxial::new(12.0, -8.0),
+            ] {
+                let round_trip = grid.world_to_frac(grid.frac_to_world(coord));
+                assert_f64_near!(round_trip.q, coord.q, 8);
+                assert_f64_near!(round_trip.r, coord.r, 8);
+            }
+
+            // Rounding agrees with the nearest tile.
+            for coord in [axial!(0, 0), axial!(12, -8), axial!(-3, 7)] {
+                let ws = grid.hex_to_world(coord);
+                assert_eq!(grid.world_to_frac((ws.0 + 1.0, ws.1 - 2.0)).round(), coord);
+            }
+        }
+    }
+
+    #[test]
+    fn vertex_to_world() {
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let grid = WSConverter {
+                size: 10.0,
+                orientation,
+            };
+
+            for coord in [axial!(0, 0), axial!(8, -12), axial!(-3, 7)] {
+                let center = grid.hex_to_world(coord);
+                assert_f64_tuples_near!(grid.vertex_to_world(Vertex::from(coord)), center);
+
+                // Corners sit a size away from the center.
+                for vertex in coord.vertices() {
+                    let (x, y) = grid.vertex_to_world(vertex);
+                    assert_float_absolute_eq!(f64::hypot(x - center.0, y - center.1), 10.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn offset_layout() {
+        for parity in [OffsetParity::Odd, OffsetParity::Even] {
+            for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+                let layout = orientation.offset_layout(parity);
+                assert_eq!(layout.parity(), parity);
+                assert_eq!(HexOrientation::from(layout), orientation);
+            }
+        }
+    }
+
+    #[test]
+    fn world_to_offset() {
+        let grid = WSConverter {
+            size: 10.0,
+            orientation: HexOrientation::PointyTop,
+        };
+
+        assert_eq!(
+            grid.world_to_offset((SQRT_3 * 56.0, -470.0), OffsetParity::Odd),
+            axial!(21, -31).to_offset(OffsetLayout::OddR)
+        );
+        assert_eq!(
+            grid.world_to_offset((0.0, 640.0), OffsetParity::Even),
+            axial!(-21, 42).to_offset(OffsetLayout::EvenR)
+        );
+
+        let grid = WSConverter {
+            orientation: HexOrientation::FlatTop,
+            ..grid
+        };
+
+        assert_eq!(
+            grid.world_to_offset((0.0, 640.0), OffsetParity::Odd),
+            axial!(0, 37).to_offset(OffsetLayout::OddQ)
+        );
+    }
+
+    #[test]
+    fn offset_to_world() {
+        let grid = WSConverter {
+            size: 10.0,
+            orientation: HexOrientation::PointyTop,
+        };
+
+        assert_f64_tuples_near!(
+            grid.offset_to_world(axial!(8, 12).to_offset(OffsetLayout::EvenR))
+                .unwrap(),
+            (SQRT_3 * 140.0, 180.0)
+        );
+        assert!(grid
+            .offset_to_world(axial!(8, 12).to_offset(OffsetLayout::EvenQ))
+            .is_none());
+
+        // Offset coordinates survive a round trip through world space.
+        for parity in [OffsetParity::Odd, OffsetParity::Even] {
+            for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+                let grid = WSConverter {
+                    orientation: orientation.clone(),
+                    ..grid
+                };
+                for coord in axial!(0, 0).range(4) {
+                    let coord = coord.to_offset(orientation.offset_layout(parity));
+                    assert_eq!(
+                        grid.world_to_offset(grid.offset_to_world(coord).unwrap(), parity),
+                        coord
+                    );
+                }
+            }
+        }
+    }
+
     macro_rules! two_way_conversion {
         ($grid:expr, $tup:expr) => {
             let (grid, tup) = ($grid, $tup);
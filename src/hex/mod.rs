@@ -2,10 +2,17 @@
 //!
 //! TODO: Examples.
 //!
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod boundary;
 pub mod coordinate;
 pub mod edge;
 #[cfg(feature = "std")]
 pub mod grid;
 #[cfg(any(feature = "std", feature = "alloc"))]
+pub mod pathfinding;
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub mod shape;
+pub mod symmetry;
+#[cfg(test)]
+mod testing;
 pub mod vertex;
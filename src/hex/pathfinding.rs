@@ -0,0 +1,432 @@
+//! Pathfinding algorithms for hex based grids.
+
+use crate::lib::*;
+
+use super::coordinate::{axial, Axial, HexDirection};
+
+/// Cost of each maneuver available to a facing aware agent.
+///
+/// See [`facing_path`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct TurnCosts {
+    /// Cost of moving one tile in the facing direction.
+    pub forward: u32,
+    /// Cost of rotating 60° CCW in place.
+    pub turn_left: u32,
+    /// Cost of rotating 60° CW in place.
+    pub turn_right: u32,
+    /// Cost of moving one tile opposite the facing direction, the facing is kept.
+    pub reverse: u32,
+}
+
+/// Default trait implementation
+///
+/// Every maneuver costs 1.
+impl Default for TurnCosts {
+    fn default() -> Self {
+        Self {
+            forward: 1,
+            turn_left: 1,
+            turn_right: 1,
+            reverse: 1,
+        }
+    }
+}
+
+/// A single action an agent can take while following a [`FacingPath`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum Maneuver {
+    /// Move one tile in the facing direction.
+    Forward,
+    /// Rotate 60° CCW in place.
+    TurnLeft,
+    /// Rotate 60° CW in place.
+    TurnRight,
+    /// Move one tile opposite the facing direction.
+    Reverse,
+}
+
+/// A step along a [`FacingPath`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Debug)]
+pub struct FacingStep {
+    /// The maneuver performed.
+    pub maneuver: Maneuver,
+    /// Coordinate of the agent after the maneuver.
+    pub coord: Axial,
+    /// Facing of the agent after the maneuver.
+    pub facing: HexDirection,
+}
+
+/// Result of [`facing_path`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Debug)]
+pub struct FacingPath {
+    /// Steps in order of traversal, the starting state is not included.
+    pub steps: Vec<FacingStep>,
+    /// Summed cost of every step.
+    pub cost: u32,
+}
+
+// Search state of (q, r, facing)
+type State = (i32, i32, i32);
+
+// Best known cost to a state and the state and maneuver it was reached from.
+type Visited = BTreeMap<State, (u32, Option<(State, Maneuver)>)>;
+
+/// Find the cheapest path for an agent that has a facing.
+///
+/// The search is performed over ([`Axial`], [`HexDirection`]) states where each [`Maneuver`]
+/// is priced according to `costs`. Facings use the same rotation arithmetic as
+/// [`HexDirection`]'s `From<i32>`, turning right is a single CW rotation.
+///
+/// `goal_facing` can be provided to require the agent to arrive with a specific facing.
+///
+/// The `passable` functor returns true if the agent may enter a coordinate. Hex grids
+/// are unbounded so the functor **must** bound the search area, otherwise an unreachable
+/// goal will search forever.
+///
+/// Returns [`None`] if the goal cannot be reached, including when every route costs more than a
+/// [`u32`] can hold.
+///
+/// # Example
+/// ```
+/// use gridava::hex::coordinate::{Axial, HexDirection, axial};
+/// use gridava::hex::pathfinding::{facing_path, Maneuver, TurnCosts};
+///
+/// let path = facing_path(
+///     (axial!(0, 0), HexDirection::Front),
+///     axial!(0, 1),
+///     None,
+///     TurnCosts::default(),
+///     |coord| coord.distance(axial!(0, 0)) <= 3,
+/// )
+/// .unwrap();
+///
+/// // Turn to face the positive r axis then move into it.
+/// assert_eq!(path.cost, 2);
+/// assert_eq!(path.steps[0].maneuver, Maneuver::TurnRight);
+/// assert_eq!(path.steps[1].maneuver, Maneuver::Forward);
+/// assert_eq!(path.steps[1].facing, HexDirection::FrontRight);
+/// ```
+pub fn facing_path<F>(
+    start: (Axial, HexDirection),
+    goal: Axial,
+    goal_facing: Option<HexDirection>,
+    costs: TurnCosts,
+    mut passable: F,
+) -> Option<FacingPath>
+where
+    F: FnMut(Axial) -> bool,
+{
+    let goal_facing = goal_facing.map(i32::from);
+    let start: State = (start.0.q, start.0.r, i32::from(start.1));
+
+    let is_goal = |(q, r, f): State| q == goal.q && r == goal.r && goal_facing.unwrap_or(f) == f;
+
+    // Every tile traversed costs at least this much, keeping the heuristic admissible.
+    let min_move = costs.forward.min(costs.reverse);
+    let heuristic =
+        |(q, r, _): State| (axial!(q, r).distance(goal) as u32).saturating_mul(min_move);
+
+    let mut visited = Visited::new();
+    let mut open = BinaryHeap::new();
+
+    visited.insert(start, (0, None));
+    open.push(Reverse((heuristic(start), 0, start)));
+
+    while let Some(Reverse((_, cost, state))) = open.pop() {
+        if visited.get(&state).is_some_and(|(best, _)| *best < cost) {
+            // Stale entry, a cheaper route was already expanded.
+            continue;
+        }
+
+        if is_goal(state) {
+            return Some(FacingPath {
+                steps: reconstruct(&visited, state),
+                cost,
+            });
+        }
+
+        let (q, r, f) = state;
+        let vec = HexDirection::from(f).to_movement_vector();
+
+        let candidates = [
+            (Maneuver::Forward, (q + vec.q, r + vec.r, f), costs.forward),
+            (Maneuver::Reverse, (q - vec.q, r - vec.r, f), costs.reverse),
+            (
+                Maneuver::TurnLeft,
+                (q, r, (f - 1).rem_euclid(6)),
+                costs.turn_left,
+            ),
+            (
+                Maneuver::TurnRight,
+                (q, r, (f + 1).rem_euclid(6)),
+                costs.turn_right,
+            ),
+        ];
+
+        for (maneuver, next, step_cost) in candidates {
+            let moved = matches!(maneuver, Maneuver::Forward | Maneuver::Reverse);
+            if moved && !passable(axial!(next.0, next.1)) {
+                continue;
+            }
+
+            // Routes costing more than a u32 can hold are never cheapest, skip them.
+            let Some(next_cost) = cost.checked_add(step_cost) else {
+                continue;
+            };
+            let cheaper = match visited.get(&next) {
+                Some((best, _)) => next_cost < *best,
+                None => true,
+            };
+            if cheaper {
+                visited.insert(next, (next_cost, Some((state, maneuver))));
+                open.push(Reverse((
+                    next_cost.saturating_add(heuristic(next)),
+                    next_cost,
+                    next,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+// Walk the visited map back from the goal to produce the steps in traversal order.
+fn reconstruct(visited: &Visited, goal: State) -> Vec<FacingStep> {
+    let mut steps = Vec::new();
+    let mut current = goal;
+
+    while let Some((_, Some((prev, maneuver)))) = visited.get(&current) {
+        steps.push(FacingStep {
+            maneuver: *maneuver,
+            coord: axial!(current.0, current.1),
+            facing: HexDirection::from(current.2),
+        });
+        current = *prev;
+    }
+
+    steps.reverse();
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounded(radius: i32) -> impl FnMut(Axial) -> bool {
+        move |coord| coord.distance(axial!(0, 0)) <= radius
+    }
+
+    #[test]
+    fn turn_costs_default() {
+        assert_eq!(
+            TurnCosts::default(),
+            TurnCosts {
+                forward: 1,
+                turn_left: 1,
+                turn_right: 1,
+                reverse: 1
+            }
+        );
+    }
+
+    #[test]
+    fn facing_path_start_is_goal() {
+        let path = facing_path(
+            (axial!(1, 1), HexDirection::Back),
+            axial!(1, 1),
+            None,
+            TurnCosts::default(),
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 0);
+        assert!(path.steps.is_empty());
+    }
+
+    #[test]
+    fn facing_path_straight() {
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(3, 0),
+            None,
+            TurnCosts::default(),
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(
+            path.steps,
+            vec![
+                FacingStep {
+                    maneuver: Maneuver::Forward,
+                    coord: axial!(1, 0),
+                    facing: HexDirection::Front
+                },
+                FacingStep {
+                    maneuver: Maneuver::Forward,
+                    coord: axial!(2, 0),
+                    facing: HexDirection::Front
+                },
+                FacingStep {
+                    maneuver: Maneuver::Forward,
+                    coord: axial!(3, 0),
+                    facing: HexDirection::Front
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn facing_path_turns() {
+        // Turning left is cheaper so the agent should rotate CCW to face BackLeft.
+        let costs = TurnCosts {
+            turn_left: 1,
+            turn_right: 5,
+            ..Default::default()
+        };
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(0, -1),
+            None,
+            costs,
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(
+            path.steps
+                .iter()
+                .map(|step| step.maneuver)
+                .collect::<Vec<_>>(),
+            vec![Maneuver::TurnLeft, Maneuver::TurnLeft, Maneuver::Forward]
+        );
+        assert_eq!(path.steps[2].facing, HexDirection::BackLeft);
+
+        // Right turns are cheaper, going the long way around is preferable.
+        let costs = TurnCosts {
+            turn_left: 10,
+            turn_right: 1,
+            ..Default::default()
+        };
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(0, -1),
+            Some(HexDirection::BackLeft),
+            costs,
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.steps.last().unwrap().facing, HexDirection::BackLeft);
+    }
+
+    #[test]
+    fn facing_path_reverse() {
+        // Reversing is cheaper than turning around.
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(-2, 0),
+            None,
+            TurnCosts::default(),
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 2);
+        assert!(path
+            .steps
+            .iter()
+            .all(|step| step.maneuver == Maneuver::Reverse && step.facing == HexDirection::Front));
+
+        // Reversing is expensive, turning around is preferable.
+        let costs = TurnCosts {
+            reverse: 10,
+            ..Default::default()
+        };
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(-2, 0),
+            None,
+            costs,
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.steps.last().unwrap().facing, HexDirection::Back);
+    }
+
+    #[test]
+    fn facing_path_goal_facing() {
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(1, 0),
+            Some(HexDirection::Back),
+            TurnCosts::default(),
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.steps.last().unwrap().coord, axial!(1, 0));
+        assert_eq!(path.steps.last().unwrap().facing, HexDirection::Back);
+    }
+
+    #[test]
+    fn facing_path_obstacles() {
+        // Wall off the tile directly in front, the agent must route around it.
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(2, 0),
+            None,
+            TurnCosts::default(),
+            |coord| coord != axial!(1, 0) && coord.distance(axial!(0, 0)) <= 3,
+        )
+        .unwrap();
+        assert!(path.steps.iter().all(|step| step.coord != axial!(1, 0)));
+        assert_eq!(path.steps.last().unwrap().coord, axial!(2, 0));
+        assert_eq!(path.cost, 6);
+
+        // Goal is out of bounds.
+        assert!(facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(5, 0),
+            None,
+            TurnCosts::default(),
+            bounded(3),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn facing_path_large_costs() {
+        let costs = TurnCosts {
+            forward: u32::MAX / 2,
+            turn_left: u32::MAX,
+            turn_right: u32::MAX,
+            reverse: u32::MAX,
+        };
+
+        // Two steps forward fit, a third does not.
+        let path = facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(2, 0),
+            None,
+            costs,
+            bounded(3),
+        )
+        .unwrap();
+        assert_eq!(path.cost, u32::MAX - 1);
+        assert!(facing_path(
+            (axial!(0, 0), HexDirection::Front),
+            axial!(3, 0),
+            None,
+            costs,
+            bounded(3),
+        )
+        .is_none());
+    }
+}
@@ -11,7 +11,11 @@ use crate::{
     transform, vector2d,
 };
 
-use super::coordinate::Axial;
+use super::{
+    boundary::{trace_boundary, BoundaryLoop},
+    coordinate::{Axial, HexDirection},
+    symmetry::SymmetryCenter,
+};
 
 /// A shape is a collection of coordinates.
 ///
@@ -235,6 +239,55 @@ impl<T: Clone> HexShape<T> {
         )
     }
 
+    /// Create a cone shape.
+    ///
+    /// Given a size, direction and width in sextants this will create a cone, see [`Axial::cone`].
+    /// The apex is at the local coordinate `(-q_min, -r_min)` of the cone's hexes so the cone fits
+    /// within the array, rotate about it to turn the cone in place.
+    ///
+    /// ```
+    /// use gridava::core::tile::Tile;
+    /// use gridava::hex::shape::HexShape;
+    ///
+    /// /// Creates a 120° cone of size 2, 0-2 inclusive, and sets the tiles to Some(1)
+    /// let my_shape = HexShape::make_cone(2, 0, 2, true, |_| Tile::new(Some(1)));
+    /// ```
+    pub fn make_cone<F>(
+        size: u32,
+        rot_dir: i32,
+        width: u32,
+        square_bb: bool,
+        mut constructor: F,
+    ) -> Self
+    where
+        F: FnMut(Axial) -> T,
+    {
+        // Working in local space
+        let hexes = axial!(0, 0).cone(HexDirection::from(rot_dir), width, size as i32);
+
+        let q_min = hexes.iter().map(|coord| coord.q).min().unwrap_or_default();
+        let q_max = hexes.iter().map(|coord| coord.q).max().unwrap_or_default();
+        let r_min = hexes.iter().map(|coord| coord.r).min().unwrap_or_default();
+        let r_max = hexes.iter().map(|coord| coord.r).max().unwrap_or_default();
+
+        let size = match square_bb {
+            true => {
+                let largest = (q_max - q_min).max(r_max - r_min) as usize;
+                (largest + 1, largest + 1)
+            }
+            false => ((q_max - q_min) as usize + 1, (r_max - r_min) as usize + 1),
+        };
+
+        let mut arr = Array::from_shape_simple_fn(size, || None);
+
+        for coord in hexes {
+            let local = axial!(coord.q - q_min, coord.r - r_min);
+            arr[[local.q as usize, local.r as usize]] = Some(constructor(local));
+        }
+
+        HexShape::new(Some(arr), Some(transform!(axial!(0, 0))))
+    }
+
     /// Translate the shape.
     ///
     /// Mutates the transform of the shape.
@@ -273,6 +326,34 @@ impl<T: Clone> HexShape<T> {
         self
     }
 
+    /// Rotate the shape about a hex, edge or vertex.
+    ///
+    /// Mutates the transform of the shape. Leaves the shape as is and produces [`None`] if the
+    /// rotation does not map the grid onto itself, see [`SymmetryCenter::allows_rotation`].
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::shape::HexShape;
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let mut my_shape: HexShape<i32> = HexShape::new(None, None);
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::DownRight);
+    /// /// Rotate the shape 120 degrees clockwise about a vertex of its origin.
+    /// assert!(my_shape.try_rotate_about(vertex, 2).is_some());
+    /// assert_eq!(my_shape.transform.translation, axial!(1, 0));
+    /// ```
+    pub fn try_rotate_about(
+        &mut self,
+        center: impl Into<SymmetryCenter>,
+        rot_dir: i32,
+    ) -> Option<&Self> {
+        self.transform.translation = self.transform.translation.rotate_about(center, rot_dir)?;
+        self.transform.rotation += rot_dir;
+        Some(self)
+    }
+
     /// Rotates the shape, either about its local origin or some point.
     ///
     /// Mutates the transform of the shape.
@@ -371,6 +452,31 @@ impl<T: Clone> HexShape<T> {
         });
     }
 
+    /// Trace the outline of the shape into closed loops.
+    ///
+    /// The coordinates are transformed according to the transform, see [`trace_boundary`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::tile::Tile;
+    /// use gridava::hex::shape::HexShape;
+    ///
+    /// let shape = HexShape::make_hexagon(1, 0, true, |_| Tile::new(Some(1)));
+    /// let loops = shape.boundary();
+    /// assert_eq!(loops.len(), 1);
+    /// assert_eq!(loops[0].edges.len(), 18);
+    /// ```
+    pub fn boundary(&self) -> Vec<BoundaryLoop> {
+        trace_boundary(
+            self.shape
+                .indexed_iter()
+                .filter(|ele| ele.1.is_some())
+                .map(|ele| {
+                    axial!(ele.0 .0 as i32, ele.0 .1 as i32).apply_transform(self.transform)
+                }),
+        )
+    }
+
     /// Get a reference to the shape's tile array.
     ///
     /// # Example
@@ -480,6 +586,44 @@ mod tests {
         assert_eq!(shape.transform.rotation, 4);
     }
 
+    #[test]
+    fn try_rotate_about() {
+        use crate::hex::{
+            edge::{edge, Edge, EdgeDirection},
+            vertex::VertexDirection,
+        };
+
+        let mut shape = HexShape::make_rhombus(1, 0, true, |_| 1);
+        let mut rotated = shape.clone();
+        rotated.rotate_about(axial!(1, 2), 2);
+        assert!(shape.try_rotate_about(axial!(1, 2), 2).is_some());
+        assert_eq!(shape, rotated);
+
+        // Rotating the shape about a vertex rotates each of its hexes about the vertex.
+        let vertex = axial!(0, 0).vertex(VertexDirection::DownRight);
+        let original = HexShape::make_triangle(2, 0, true, |_| 1);
+        let mut shape = original.clone();
+        assert!(shape.try_rotate_about(vertex, 1).is_none());
+        assert_eq!(shape, original);
+        assert!(shape.try_rotate_about(vertex, -2).is_some());
+        assert_eq!(shape.transform.rotation, -2);
+        for (index, _) in original.get_hexes().indexed_iter() {
+            let local = axial!(index.0 as i32, index.1 as i32);
+            assert_eq!(
+                local.apply_transform(shape.transform),
+                local.rotate_about(vertex, -2).unwrap()
+            );
+        }
+
+        // Rotating about an edge swaps its hexes.
+        let mut shape = HexShape::<i32>::new(None, None);
+        assert!(shape
+            .try_rotate_about(edge!(0, 1, EdgeDirection::NorthWest), 3)
+            .is_some());
+        assert_eq!(shape.transform.translation, axial!(0, 1));
+        assert_eq!(shape.transform.rotation, 3);
+    }
+
     #[test]
     fn make_shape() {
         assert_eq!(
@@ -562,6 +706,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn make_cone() {
+        assert_eq!(
+            HexShape::make_cone(1, 0, 2, false, |_| 1).get_hexes(),
+            array![[None, Some(1), Some(1)], [Some(1), Some(1), None]]
+        );
+
+        let cone = HexShape::make_cone(2, 0, 1, false, |_| 1);
+        assert_eq!(
+            cone.get_hexes(),
+            array![
+                [None, Some(1), None],
+                [None, Some(1), Some(1)],
+                [Some(1), Some(1), None]
+            ]
+        );
+
+        // Rotating the cone about its apex matches a cone facing the rotated direction.
+        let mut col = MockCollection {
+            tiles: Default::default(),
+        };
+        let mut cone = HexShape::make_cone(2, 0, 1, false, |_| 1);
+        cone.rotate(Some(axial!(0, 1)), 2);
+        cone.apply_shape(&mut col);
+
+        let mut coords = col.tiles.into_keys().collect::<Vec<_>>();
+        coords.sort_by_key(|coord| (coord.q, coord.r));
+        assert_eq!(coords, axial!(0, 1).cone(HexDirection::BackRight, 1, 2));
+    }
+
     #[test]
     fn scale() {
         assert_eq!(
@@ -611,6 +785,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn boundary() {
+        let mut col = MockCollection {
+            tiles: Default::default(),
+        };
+        let mut shape = HexShape::make_triangle(2, 0, true, |_| 1);
+        shape.translate(axial!(3, -5));
+        shape.apply_shape(&mut col);
+
+        // Agrees with the boundary of the transformed coordinates.
+        let loops = shape.boundary();
+        assert_eq!(loops, trace_boundary(col.tiles.keys().copied()));
+        assert_eq!(loops.len(), 1);
+        assert!(!loops[0].is_hole());
+    }
+
     #[test]
     fn get_hexes() {
         let shape = HexShape::make_rhombus(1, 0, true, |_| 1);
@@ -0,0 +1,841 @@
+//! Symmetries of the hex grid about hexes, edges and vertices.
+//!
+//! Hex centers, edge midpoints and vertices all lie on a common lattice, six times finer than the
+//! hex grid. Symmetries are applied on that lattice and mapped back, which allows the center of
+//! a symmetry to be any of the three.
+
+use crate::lib::*;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::core::collection::Collection;
+
+use super::{
+    coordinate::{axial, Axes, Axial},
+    edge::{edge, Edge, EdgeDirection},
+    vertex::Vertex,
+};
+
+/// The six mirror lines of a hexagon.
+///
+/// Lines are named after the neighbor, or the diagonal, they pass through on the forward side
+/// of the hexagon. Moving to the next line rotates it 30° CW, see `From<i32>`.
+///
+/// Lines through neighbors cross the edges of the hexagon at their midpoints, lines through
+/// diagonals cross its vertices.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum MirrorLine {
+    /// Line through the [`Front`](super::coordinate::HexDirection::Front) and back neighbors.
+    Front,
+    /// Line through the [`DownRight`](super::coordinate::HexDiagonal::DownRight) and up left
+    /// diagonals.
+    DownRight,
+    /// Line through the [`FrontRight`](super::coordinate::HexDirection::FrontRight) and back
+    /// left neighbors.
+    FrontRight,
+    /// Line through the [`Down`](super::coordinate::HexDiagonal::Down) and up diagonals.
+    Down,
+    /// Line through the [`BackRight`](super::coordinate::HexDirection::BackRight) and front left
+    /// neighbors.
+    BackRight,
+    /// Line through the [`DownLeft`](super::coordinate::HexDiagonal::DownLeft) and up right
+    /// diagonals.
+    DownLeft,
+}
+
+impl From<i32> for MirrorLine {
+    fn from(value: i32) -> Self {
+        match value.rem_euclid(6) {
+            0 => MirrorLine::Front,
+            1 => MirrorLine::DownRight,
+            2 => MirrorLine::FrontRight,
+            3 => MirrorLine::Down,
+            4 => MirrorLine::BackRight,
+            5 => MirrorLine::DownLeft,
+            _ => unreachable!(), // should never reach
+        }
+    }
+}
+
+impl From<MirrorLine> for i32 {
+    fn from(value: MirrorLine) -> Self {
+        match value {
+            MirrorLine::Front => 0,
+            MirrorLine::DownRight => 1,
+            MirrorLine::FrontRight => 2,
+            MirrorLine::Down => 3,
+            MirrorLine::BackRight => 4,
+            MirrorLine::DownLeft => 5,
+        }
+    }
+}
+
+/// The mirror line [`Axial::reflect`] reflects across for each axis.
+impl From<Axes> for MirrorLine {
+    fn from(value: Axes) -> Self {
+        match value {
+            Axes::Q => MirrorLine::DownLeft,
+            Axes::R => MirrorLine::Down,
+            Axes::S => MirrorLine::DownRight,
+        }
+    }
+}
+
+/// A point of the grid a symmetry can be centered on.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum SymmetryCenter {
+    /// The center of a hex.
+    Hex(Axial),
+    /// The midpoint of an edge.
+    Edge(Edge),
+    /// A vertex.
+    Vertex(Vertex),
+}
+
+impl Default for SymmetryCenter {
+    fn default() -> Self {
+        SymmetryCenter::Hex(Axial::default())
+    }
+}
+
+impl From<Axial> for SymmetryCenter {
+    fn from(value: Axial) -> Self {
+        SymmetryCenter::Hex(value)
+    }
+}
+
+impl From<Edge> for SymmetryCenter {
+    fn from(value: Edge) -> Self {
+        SymmetryCenter::Edge(value)
+    }
+}
+
+impl From<Vertex> for SymmetryCenter {
+    fn from(value: Vertex) -> Self {
+        SymmetryCenter::Vertex(value)
+    }
+}
+
+// Lattice points are stored as axial coordinates scaled by LATTICE_SCALE. They are widened to
+// i64 so that the lattice points of every i32 coordinate, and their images, fit.
+pub(crate) type LatticePoint = Axial<i64>;
+
+const LATTICE_SCALE: i64 = 6;
+
+pub(crate) fn hex_to_lattice(coord: Axial) -> LatticePoint {
+    coord.cast::<i64>() * LATTICE_SCALE
+}
+
+pub(crate) fn hex_from_lattice(point: LatticePoint) -> Option<Axial> {
+    match point.q % LATTICE_SCALE == 0 && point.r % LATTICE_SCALE == 0 {
+        true => (point / LATTICE_SCALE).try_cast(),
+        false => None,
+    }
+}
+
+// An edge midpoint is half the sum of its two hexes.
+pub(crate) fn edge_to_lattice(edge: Edge) -> LatticePoint {
+    let [a, b] = edge.adjacent_hexes();
+    (a.cast::<i64>() + b.cast()) * (LATTICE_SCALE / 2)
+}
+
+pub(crate) fn edge_from_lattice(point: LatticePoint) -> Option<Edge> {
+    if point.q % 3 != 0 || point.r % 3 != 0 {
+        return None;
+    }
+
+    // The parity of the sum of the two hexes determines the edge direction, see
+    // Edge::adjacent_hexes.
+    let sum = point / 3;
+    let (q, r, dir) = match (sum.q.rem_euclid(2), sum.r.rem_euclid(2)) {
+        (1, 0) => ((sum.q + 1) / 2, sum.r / 2, EdgeDirection::West),
+        (0, 1) => (sum.q / 2, (sum.r + 1) / 2, EdgeDirection::NorthWest),
+        (1, 1) => ((sum.q - 1) / 2, (sum.r + 1) / 2, EdgeDirection::NorthEast),
+        _ => return None, // hex center
+    };
+    Some(edge!(i32::try_from(q).ok()?, i32::try_from(r).ok()?, dir))
+}
+
+// A vertex is the centroid of its three hexes. The orientation of the triangle coordinate,
+// its sum, offsets the coordinate from three times the centroid along (1, 1, 1).
+pub(crate) fn vertex_to_lattice(vertex: Vertex) -> LatticePoint {
+    let coord = vertex.coord.cast::<i64>();
+    let sum = coord.x + coord.y + coord.z;
+    axial!(3 * coord.x - sum, 3 * coord.y - sum) * (LATTICE_SCALE / 3)
+}
+
+pub(crate) fn vertex_from_lattice(point: LatticePoint) -> Option<Vertex> {
+    if point.q % 2 != 0 || point.r % 2 != 0 {
+        return None;
+    }
+
+    let tripled = point / 2;
+    let sum = (-tripled.q).rem_euclid(3);
+    if sum == 0 || (tripled.r + sum) % 3 != 0 {
+        return None; // hex center or off the lattice
+    }
+
+    let x = (tripled.q + sum) / 3;
+    let y = (tripled.r + sum) / 3;
+    Vertex::new(x, y, sum - x - y).try_cast()
+}
+
+impl SymmetryCenter {
+    pub(crate) fn to_lattice(self) -> LatticePoint {
+        match self {
+            SymmetryCenter::Hex(coord) => hex_to_lattice(coord),
+            SymmetryCenter::Edge(edge) => edge_to_lattice(edge),
+            SymmetryCenter::Vertex(vertex) => vertex_to_lattice(vertex),
+        }
+    }
+
+    /// Number of rotations about the center that map the grid onto itself.
+    ///
+    /// 6 about a hex, 3 about a vertex and 2 about an edge.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::symmetry::SymmetryCenter;
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// assert_eq!(SymmetryCenter::from(vertex).rotation_order(), 3);
+    /// ```
+    pub fn rotation_order(self) -> i32 {
+        // Classify the point itself, a vertex that is a hex center behaves as a hex.
+        let point = self.to_lattice();
+        if hex_from_lattice(point).is_some() {
+            6
+        } else if vertex_from_lattice(point).is_some() {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// Whether rotating about the center maps the grid onto itself.
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    pub fn allows_rotation(self, rot_dir: i32) -> bool {
+        rot_dir % (6 / self.rotation_order()) == 0
+    }
+
+    // Rotate a lattice point about the center.
+    pub(crate) fn rotate_lattice(self, point: LatticePoint, rot_dir: i32) -> Option<LatticePoint> {
+        if !self.allows_rotation(rot_dir) {
+            return None;
+        }
+
+        let center = self.to_lattice();
+        Some((point - center).rotate(None, rot_dir) + center)
+    }
+}
+
+impl MirrorLine {
+    // Mirror a lattice point about a lattice center.
+    pub(crate) fn mirror_lattice(self, point: LatticePoint, center: LatticePoint) -> LatticePoint {
+        // Mirroring across the Front line negates r and keeps the row position, q + r / 2.
+        let local = point - center;
+        let mirrored = axial!(local.q + local.r, -local.r);
+
+        // Mirroring across a line rotated by 30° is a mirror across the Front line followed by a
+        // 60° rotation.
+        mirrored.rotate(None, self.into()) + center
+    }
+}
+
+impl Axial {
+    /// Mirror the coordinate across one of the six mirror lines of a hex.
+    ///
+    /// `center` can be provided to specify the hex the line passes through. Otherwise, (0, 0) will
+    /// be used.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::symmetry::MirrorLine;
+    ///
+    /// assert_eq!(axial!(0, 1).mirror(None, MirrorLine::Front), axial!(1, -1));
+    /// assert_eq!(axial!(1, 0).mirror(None, MirrorLine::Down), axial!(-1, 0));
+    /// ```
+    pub fn mirror(&self, center: Option<Self>, line: MirrorLine) -> Self {
+        let center = center.unwrap_or_default();
+        let vec = *self - center;
+        let (q, r, s) = (vec.q, vec.r, vec.compute_s());
+
+        // Mirroring across the Front line swaps q and s and negates every component. Each
+        // following line rotates the result a further 60° CW, see Axial::rotate.
+        let mirrored = match line {
+            MirrorLine::Front => axial!(-s, -r),
+            MirrorLine::DownRight => axial!(r, q),
+            MirrorLine::FrontRight => axial!(-q, -s),
+            MirrorLine::Down => axial!(s, r),
+            MirrorLine::BackRight => axial!(-r, -q),
+            MirrorLine::DownLeft => axial!(q, s),
+        };
+
+        mirrored + center
+    }
+
+    /// Mirror the coordinate across a mirror line passing through a hex, edge or vertex.
+    ///
+    /// Produces [`None`] if the line does not map the grid onto itself, e.g. a line through a
+    /// vertex that does not also pass through a hex center.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    /// use gridava::hex::symmetry::MirrorLine;
+    ///
+    /// // The edge between (0, 0) and (1, 0) swaps the two hexes.
+    /// let edge = edge!(1, 0, EdgeDirection::West);
+    /// assert_eq!(axial!(0, 0).mirror_about(edge, MirrorLine::Down), Some(axial!(1, 0)));
+    /// assert_eq!(axial!(0, 0).mirror_about(edge, MirrorLine::FrontRight), None);
+    /// ```
+    pub fn mirror_about(
+        &self,
+        center: impl Into<SymmetryCenter>,
+        line: MirrorLine,
+    ) -> Option<Self> {
+        let center = center.into().to_lattice();
+        hex_from_lattice(line.mirror_lattice(hex_to_lattice(*self), center))
+    }
+
+    /// Rotate the coordinate about a hex, edge or vertex.
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// Produces [`None`] if the rotation does not map the grid onto itself, see
+    /// [`SymmetryCenter::allows_rotation`]. Rotations about a vertex must be multiples of 120°,
+    /// about an edge multiples of 180°.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// // The three hexes around a vertex cycle.
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::DownRight);
+    /// assert_eq!(axial!(0, 0).rotate_about(vertex, 2), Some(axial!(1, 0)));
+    /// assert_eq!(axial!(1, 0).rotate_about(vertex, 2), Some(axial!(0, 1)));
+    /// assert_eq!(axial!(0, 0).rotate_about(vertex, 1), None);
+    ///
+    /// // The two hexes of an edge swap.
+    /// let edge = edge!(1, 0, EdgeDirection::West);
+    /// assert_eq!(axial!(0, 0).rotate_about(edge, 3), Some(axial!(1, 0)));
+    /// ```
+    pub fn rotate_about(&self, center: impl Into<SymmetryCenter>, rot_dir: i32) -> Option<Self> {
+        center
+            .into()
+            .rotate_lattice(hex_to_lattice(*self), rot_dir)
+            .and_then(hex_from_lattice)
+    }
+}
+
+impl Edge {
+    /// Mirror the edge across one of the six mirror lines of a hex.
+    ///
+    /// `center` can be provided to specify the hex the line passes through. Otherwise, (0, 0) will
+    /// be used.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    /// use gridava::hex::symmetry::MirrorLine;
+    ///
+    /// let edge = edge!(0, 0, EdgeDirection::NorthWest);
+    /// assert_eq!(edge.mirror(None, MirrorLine::Down), edge!(0, 0, EdgeDirection::NorthEast));
+    /// ```
+    pub fn mirror(&self, center: Option<Axial>, line: MirrorLine) -> Self {
+        // Mirroring a direction across a line reflects its angle about the angle of the line.
+        self.transform_about_hex(|hex| hex.mirror(center, line), |dir| i32::from(line) - dir)
+    }
+
+    /// Mirror the edge across a mirror line passing through a hex, edge or vertex.
+    ///
+    /// Produces [`None`] if the line does not map the grid onto itself.
+    pub fn mirror_about(
+        &self,
+        center: impl Into<SymmetryCenter>,
+        line: MirrorLine,
+    ) -> Option<Self> {
+        let center = center.into().to_lattice();
+        edge_from_lattice(line.mirror_lattice(edge_to_lattice(*self), center))
+    }
+
+    /// Rotate the edge about a hex, edge or vertex.
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// Produces [`None`] if the rotation does not map the grid onto itself, see
+    /// [`SymmetryCenter::allows_rotation`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::edge::{Edge, EdgeDirection, edge};
+    ///
+    /// let edge = edge!(1, 0, EdgeDirection::West);
+    /// assert_eq!(edge.rotate_about(edge, 3), Some(edge));
+    /// ```
+    pub fn rotate_about(&self, center: impl Into<SymmetryCenter>, rot_dir: i32) -> Option<Self> {
+        center
+            .into()
+            .rotate_lattice(edge_to_lattice(*self), rot_dir)
+            .and_then(edge_from_lattice)
+    }
+}
+
+impl Vertex {
+    /// Mirror the vertex across one of the six mirror lines of a hex.
+    ///
+    /// `center` can be provided to specify the hex the line passes through. Otherwise, (0, 0) will
+    /// be used.
+    ///
+    /// Coordinates of a hex center, see
+    /// [`Triangle::is_tri_face()`](crate::triangle::coordinate::Triangle::is_tri_face), are
+    /// mirrored as that hex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::symmetry::MirrorLine;
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::UpRight);
+    /// assert_eq!(
+    ///     vertex.mirror(None, MirrorLine::Down),
+    ///     axial!(0, 0).vertex(VertexDirection::UpLeft)
+    /// );
+    /// ```
+    pub fn mirror(&self, center: Option<Axial>, line: MirrorLine) -> Self {
+        // Vertex directions are 90° CCW of the hex directions of the same index.
+        self.transform_about_hex(
+            |hex| hex.mirror(center, line),
+            |dir| i32::from(line) + 3 - dir,
+        )
+    }
+
+    /// Mirror the vertex across a mirror line passing through a hex, edge or vertex.
+    ///
+    /// Produces [`None`] if the line does not map the grid onto itself, or this is not a vertex,
+    /// see [`Triangle::is_tri_face()`](crate::triangle::coordinate::Triangle::is_tri_face).
+    pub fn mirror_about(
+        &self,
+        center: impl Into<SymmetryCenter>,
+        line: MirrorLine,
+    ) -> Option<Self> {
+        let center = center.into().to_lattice();
+        vertex_from_lattice(line.mirror_lattice(vertex_to_lattice(*self), center))
+    }
+
+    /// Rotate the vertex about a hex, edge or vertex.
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// Produces [`None`] if the rotation does not map the grid onto itself, see
+    /// [`SymmetryCenter::allows_rotation`], or this is not a vertex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let center = axial!(0, 0).vertex(VertexDirection::DownRight);
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// assert_eq!(
+    ///     vertex.rotate_about(center, 2),
+    ///     Some(axial!(1, 0).vertex(VertexDirection::DownRight))
+    /// );
+    /// ```
+    pub fn rotate_about(&self, center: impl Into<SymmetryCenter>, rot_dir: i32) -> Option<Self> {
+        center
+            .into()
+            .rotate_lattice(vertex_to_lattice(*self), rot_dir)
+            .and_then(vertex_from_lattice)
+    }
+}
+
+/// Rotate a group of hexes about a hex, edge or vertex and set them into a collection.
+///
+/// Each hex of `group` is set at its rotated coordinate with its data. Hexes the group no longer
+/// covers are left as they are, rotating a group onto itself, e.g. the three hexes around a
+/// vertex, replaces all of them.
+///
+/// Returns `false`, without touching the collection, if the rotation does not map the grid onto
+/// itself, see [`SymmetryCenter::allows_rotation`], or if any rotated hex does not fit in an
+/// [`i32`].
+///
+/// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn rotate_group<T, COL: Collection<Axial, T>>(
+    group: impl IntoIterator<Item = (Axial, T)>,
+    center: impl Into<SymmetryCenter>,
+    rot_dir: i32,
+    col: &mut COL,
+) -> bool {
+    let center = center.into();
+    if !center.allows_rotation(rot_dir) {
+        return false;
+    }
+
+    // Rotate the whole group first so a hex that does not fit leaves the collection untouched.
+    let Some(rotated) = group
+        .into_iter()
+        .map(|(coord, data)| Some((coord.rotate_about(center, rot_dir)?, data)))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+
+    for (coord, data) in rotated {
+        col.set(coord, data);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex::testing::assert_follows_hexes;
+    use crate::hex::vertex::VertexDirection;
+
+    const LINES: [MirrorLine; 6] = [
+        MirrorLine::Front,
+        MirrorLine::DownRight,
+        MirrorLine::FrontRight,
+        MirrorLine::Down,
+        MirrorLine::BackRight,
+        MirrorLine::DownLeft,
+    ];
+
+    #[test]
+    fn from_i32() {
+        for (dir, line) in LINES.iter().enumerate() {
+            assert_eq!(MirrorLine::from(dir as i32), *line);
+            assert_eq!(i32::from(*line), dir as i32);
+        }
+        assert_eq!(MirrorLine::from(-1), MirrorLine::DownLeft);
+    }
+
+    #[test]
+    fn lattice() {
+        for coord in axial!(0, 0).range_iter(3) {
+            assert_eq!(hex_from_lattice(hex_to_lattice(coord)), Some(coord));
+            assert_eq!(edge_from_lattice(hex_to_lattice(coord)), None);
+            assert_eq!(vertex_from_lattice(hex_to_lattice(coord)), None);
+
+            for edge in coord.edges() {
+                assert_eq!(edge_from_lattice(edge_to_lattice(edge)), Some(edge));
+                assert_eq!(hex_from_lattice(edge_to_lattice(edge)), None);
+            }
+            for vertex in coord.vertices() {
+                assert_eq!(vertex_from_lattice(vertex_to_lattice(vertex)), Some(vertex));
+                assert_eq!(edge_from_lattice(vertex_to_lattice(vertex)), None);
+            }
+        }
+    }
+
+    #[test]
+    fn mirror() {
+        let center = axial!(2, -1);
+        for line in LINES {
+            for coord in center.range_iter(3) {
+                assert_eq!(
+                    coord.mirror(Some(center), line).mirror(Some(center), line),
+                    coord
+                );
+                assert_eq!(
+                    coord.mirror(Some(center), line).distance(center),
+                    coord.distance(center)
+                );
+                assert_eq!(
+                    coord.mirror_about(center, line),
+                    Some(coord.mirror(Some(center), line))
+                );
+            }
+        }
+
+        // Lines through neighbors keep them in place, lines through diagonals keep the diagonals.
+        assert_eq!(axial!(2, 0).mirror(None, MirrorLine::Front), axial!(2, 0));
+        assert_eq!(
+            axial!(0, 1).mirror(None, MirrorLine::FrontRight),
+            axial!(0, 1)
+        );
+        assert_eq!(
+            axial!(-1, 1).mirror(None, MirrorLine::BackRight),
+            axial!(-1, 1)
+        );
+        assert_eq!(
+            axial!(1, 1).mirror(None, MirrorLine::DownRight),
+            axial!(1, 1)
+        );
+        assert_eq!(axial!(-1, 2).mirror(None, MirrorLine::Down), axial!(-1, 2));
+        assert_eq!(
+            axial!(-2, 1).mirror(None, MirrorLine::DownLeft),
+            axial!(-2, 1)
+        );
+    }
+
+    #[test]
+    fn mirror_bounds() {
+        // Coordinates past a sixth of i32 overflow an i32 lattice.
+        let coord = axial!(i32::MAX / 4, 0);
+        assert_eq!(coord.mirror(None, MirrorLine::Front), coord);
+        assert_eq!(coord.mirror(None, MirrorLine::Down), -coord);
+        assert_eq!(
+            coord.mirror_about(axial!(0, 0), MirrorLine::Down),
+            Some(-coord)
+        );
+
+        let edge = edge!(i32::MAX / 4, 0, EdgeDirection::West);
+        assert_eq!(
+            axial!(i32::MAX / 4, 0).mirror_about(edge, MirrorLine::Down),
+            Some(axial!(i32::MAX / 4 - 1, 0))
+        );
+        assert_eq!(
+            coord.rotate_about(edge, 3),
+            Some(axial!(i32::MAX / 4 - 1, 0))
+        );
+
+        // Images past the bounds of i32 do not map back.
+        assert_eq!(
+            axial!(i32::MAX, 0).mirror_about(
+                edge!(i32::MIN / 2, 0, EdgeDirection::West),
+                MirrorLine::Down
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn mirror_matches_reflect() {
+        let center = axial!(1, -3);
+        for coord in axial!(0, 0).range_iter(3) {
+            for axes in [Axes::Q, Axes::R, Axes::S] {
+                assert_eq!(
+                    coord.mirror(Some(center), axes.into()),
+                    coord.reflect(Some(center), axes)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mirror_edge_and_vertex() {
+        let center = axial!(-1, 2);
+        for line in LINES {
+            for coord in center.range_iter(2) {
+                for edge in coord.edges() {
+                    let mirrored = edge.mirror(Some(center), line);
+                    assert_eq!(mirrored.mirror(Some(center), line), edge);
+                    assert_follows_hexes(edge, mirrored, |hex| hex.mirror(Some(center), line));
+                }
+                for vertex in coord.vertices() {
+                    let mirrored = vertex.mirror(Some(center), line);
+                    assert_follows_hexes(vertex, mirrored, |hex| hex.mirror(Some(center), line));
+                }
+                assert_eq!(
+                    Vertex::from(coord).mirror(Some(center), line),
+                    Vertex::from(coord.mirror(Some(center), line))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mirror_about() {
+        // An edge has two mirror lines, a vertex three.
+        let edge = edge!(1, 0, EdgeDirection::West);
+        let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+        for (center, count) in [
+            (SymmetryCenter::from(edge), 2),
+            (SymmetryCenter::from(vertex), 3),
+            (SymmetryCenter::default(), 6),
+        ] {
+            let valid = LINES
+                .iter()
+                .filter(|line| axial!(3, 1).mirror_about(center, **line).is_some())
+                .count();
+            assert_eq!(valid, count);
+
+            // Lines mapping the grid onto itself map every hex, edge and vertex.
+            for line in LINES {
+                if axial!(3, 1).mirror_about(center, line).is_none() {
+                    continue;
+                }
+                for coord in axial!(0, 0).range_iter(2) {
+                    assert!(coord.mirror_about(center, line).is_some());
+                    assert!(coord
+                        .edges()
+                        .iter()
+                        .all(|edge| edge.mirror_about(center, line).is_some()));
+                    assert!(coord
+                        .vertices()
+                        .iter()
+                        .all(|vertex| vertex.mirror_about(center, line).is_some()));
+                }
+            }
+        }
+
+        // Mirroring about an edge swaps its hexes and keeps the edge.
+        assert_eq!(
+            axial!(1, 0).mirror_about(edge, MirrorLine::Down),
+            Some(axial!(0, 0))
+        );
+        assert_eq!(edge.mirror_about(edge, MirrorLine::Down), Some(edge));
+        assert_eq!(edge.mirror_about(edge, MirrorLine::Front), Some(edge));
+
+        // Mirroring about a vertex keeps the vertex and permutes its hexes, only lines through
+        // the hex centers map the grid onto itself.
+        for line in [
+            MirrorLine::DownRight,
+            MirrorLine::Down,
+            MirrorLine::DownLeft,
+        ] {
+            assert_eq!(vertex.mirror_about(vertex, line), Some(vertex));
+            for hex in vertex.adjacent_hexes().unwrap() {
+                let mirrored = hex.mirror_about(vertex, line).unwrap();
+                assert!(vertex.adjacent_hexes().unwrap().contains(&mirrored));
+            }
+        }
+        assert_eq!(axial!(0, 0).mirror_about(vertex, MirrorLine::Front), None);
+
+        // Not a vertex.
+        assert_eq!(
+            Vertex::new(0, 0, 0).mirror_about(axial!(0, 0), MirrorLine::Front),
+            None
+        );
+    }
+
+    #[test]
+    fn rotation_order() {
+        let edge = edge!(1, 0, EdgeDirection::West);
+        let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+        assert_eq!(SymmetryCenter::default().rotation_order(), 6);
+        assert_eq!(SymmetryCenter::from(vertex).rotation_order(), 3);
+        assert_eq!(SymmetryCenter::from(edge).rotation_order(), 2);
+        assert_eq!(
+            SymmetryCenter::from(Vertex::new(0, 0, 0)).rotation_order(),
+            6
+        );
+
+        for rot_dir in -6..=6 {
+            assert!(SymmetryCenter::default().allows_rotation(rot_dir));
+            assert_eq!(
+                SymmetryCenter::from(vertex).allows_rotation(rot_dir),
+                rot_dir % 2 == 0
+            );
+            assert_eq!(
+                SymmetryCenter::from(edge).allows_rotation(rot_dir),
+                rot_dir % 3 == 0
+            );
+        }
+    }
+
+    #[test]
+    fn rotate_about() {
+        // Rotating about a hex matches Axial::rotate.
+        let center = axial!(2, -1);
+        for coord in center.range_iter(3) {
+            for rot_dir in -6..=6 {
+                assert_eq!(
+                    coord.rotate_about(center, rot_dir),
+                    Some(coord.rotate(Some(center), rot_dir))
+                );
+            }
+        }
+
+        // Rotating about a vertex cycles its three hexes.
+        let vertex = axial!(1, -2).vertex(VertexDirection::DownLeft);
+        let hexes = vertex.adjacent_hexes().unwrap();
+        for hex in hexes {
+            let once = hex.rotate_about(vertex, 2).unwrap();
+            let twice = once.rotate_about(vertex, 2).unwrap();
+            assert!(hexes.contains(&once) && hexes.contains(&twice));
+            assert_ne!(once, hex);
+            assert_ne!(twice, hex);
+            assert_eq!(twice.rotate_about(vertex, 2), Some(hex));
+            assert_eq!(hex.rotate_about(vertex, -2), Some(twice));
+            assert_eq!(hex.rotate_about(vertex, 1), None);
+        }
+        assert_eq!(vertex.rotate_about(vertex, 2), Some(vertex));
+
+        // Rotating about an edge swaps its hexes.
+        let edge = edge!(0, 3, EdgeDirection::NorthEast);
+        let [a, b] = edge.adjacent_hexes();
+        assert_eq!(a.rotate_about(edge, 3), Some(b));
+        assert_eq!(b.rotate_about(edge, -3), Some(a));
+        assert_eq!(a.rotate_about(edge, 2), None);
+        assert_eq!(edge.rotate_about(edge, 3), Some(edge));
+
+        // Edges and vertices follow the hexes around them.
+        for (center, rot_dir) in [
+            (SymmetryCenter::from(vertex), 2),
+            (SymmetryCenter::from(vertex), -2),
+            (SymmetryCenter::from(edge), 3),
+            (SymmetryCenter::from(axial!(1, 1)), 1),
+        ] {
+            let rotate = |hex: Axial| hex.rotate_about(center, rot_dir).unwrap();
+            for coord in axial!(0, 0).range_iter(2) {
+                assert_eq!(
+                    rotate(coord).distance(rotate(axial!(0, 0))),
+                    coord.distance(axial!(0, 0))
+                );
+                for edge in coord.edges() {
+                    let rotated = edge.rotate_about(center, rot_dir).unwrap();
+                    assert_follows_hexes(edge, rotated, rotate);
+                }
+                for vertex in coord.vertices() {
+                    let rotated = vertex.rotate_about(center, rot_dir).unwrap();
+                    assert_follows_hexes(vertex, rotated, rotate);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rotate_group() {
+        use std::collections::HashMap;
+
+        struct MockCollection {
+            tiles: HashMap<Axial, char>,
+        }
+
+        impl Collection<Axial, char> for MockCollection {
+            fn set(&mut self, coord: Axial, data: char) {
+                self.tiles.insert(coord, data);
+            }
+        }
+
+        let vertex = axial!(0, 0).vertex(VertexDirection::DownRight);
+        let [a, b, c] = vertex.adjacent_hexes().unwrap();
+        let mut col = MockCollection {
+            tiles: HashMap::from([(a, 'a'), (b, 'b'), (c, 'c')]),
+        };
+
+        let group: Vec<_> = col
+            .tiles
+            .iter()
+            .map(|(coord, data)| (*coord, *data))
+            .collect();
+        assert!(!super::rotate_group(group.clone(), vertex, 1, &mut col));
+        assert!(super::rotate_group(group, vertex, 2, &mut col));
+        assert_eq!(col.tiles.len(), 3);
+        for (coord, data) in [(a, 'a'), (b, 'b'), (c, 'c')] {
+            assert_eq!(col.tiles[&coord.rotate_about(vertex, 2).unwrap()], data);
+        }
+
+        // A hex rotated beyond the range of i32 leaves the collection untouched.
+        let before = col.tiles.clone();
+        let group = [(axial!(0, 0), 'x'), (axial!(i32::MAX - 5, 0), 'y')];
+        assert!(!super::rotate_group(group, axial!(-1000, 0), 3, &mut col));
+        assert_eq!(col.tiles, before);
+    }
+}
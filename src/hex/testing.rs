@@ -0,0 +1,41 @@
+//! Helpers shared by the tests of edges, vertices and their symmetries.
+
+use super::{coordinate::Axial, edge::Edge, vertex::Vertex};
+
+/// Sort hexes so arrays of them compare as sets.
+pub(super) fn sorted<const N: usize>(mut hexes: [Axial; N]) -> [Axial; N] {
+    hexes.sort_by_key(|hex| (hex.q, hex.r));
+    hexes
+}
+
+/// Features of the grid bordered by `N` hexes.
+pub(super) trait AdjacentHexes<const N: usize>: Copy {
+    /// The bordering hexes, in any order.
+    fn hexes(self) -> [Axial; N];
+}
+
+impl AdjacentHexes<2> for Edge {
+    fn hexes(self) -> [Axial; 2] {
+        self.adjacent_hexes()
+    }
+}
+
+impl AdjacentHexes<3> for Vertex {
+    fn hexes(self) -> [Axial; 3] {
+        self.adjacent_hexes().expect("a vertex")
+    }
+}
+
+/// Assert a transformed feature is bordered by the hexes of the original moved by `transform`.
+pub(super) fn assert_follows_hexes<F, const N: usize>(
+    feature: F,
+    transformed: F,
+    transform: impl Fn(Axial) -> Axial,
+) where
+    F: AdjacentHexes<N>,
+{
+    assert_eq!(
+        sorted(transformed.hexes()),
+        sorted(feature.hexes().map(transform))
+    );
+}
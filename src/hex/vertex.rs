@@ -1,12 +1,21 @@
 //! Handles vertices in a hexagonal grid.
 
+use crate::core::parse::{self, ParseCoordError};
 use crate::lib::*;
 
+use crate::core::autotile::TriMask;
+use crate::core::coordinate::{Distance, Line, Neighbors, Range};
+use crate::core::int::SignedInt;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::core::misc::Winding;
+use crate::core::transform::Transform;
 use crate::edge;
-use crate::triangle::coordinate::{TriOrientation, Triangle};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::triangle::coordinate::TriDirection;
+use crate::triangle::coordinate::{TriOrientation, Triangle, TriangleLine, TriangleRange};
 
 use super::{
-    coordinate::{axial, Axial},
+    coordinate::{axial, Axes, Axial},
     edge::{Edge, EdgeDirection},
 };
 
@@ -84,14 +93,21 @@ impl From<VertexDirection> for i32 {
 ///
 /// To convert to axial from a vertex, use the member function [`Vertex::try_to_axial()`].
 ///
+/// Components are `i32` by default, any [`SignedInt`] can be used instead. Adjacent hexes and
+/// vertices, masks, distance, see [`Vertex::distance_t`], and ranges work for every component
+/// type. Adjacent edges, transforms, symmetry and the grid traits, e.g. [`Range`], are `i32` only.
+///
 /// See [`Triangle`] for more information.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
-pub struct Vertex {
+pub struct Vertex<T = i32> {
     /// Wrapped triangle coordinate struct used for hex vertices.
-    pub coord: Triangle,
+    pub coord: Triangle<T>,
 }
 
+/// Iterator over the vertices within a number of steps, see [`Vertex::range_iter`].
+pub type VertexRange<T = i32> = iter::Map<TriangleRange<T>, fn(Triangle<T>) -> Vertex<T>>;
+
 impl From<VertexDirection> for Vertex {
     fn from(value: VertexDirection) -> Self {
         match value {
@@ -105,37 +121,107 @@ impl From<VertexDirection> for Vertex {
     }
 }
 
-impl From<(Axial, VertexDirection)> for Vertex {
-    fn from(value: (Axial, VertexDirection)) -> Self {
+impl<T: SignedInt> From<(Axial<T>, VertexDirection)> for Vertex<T> {
+    fn from(value: (Axial<T>, VertexDirection)) -> Self {
         let vert_dir: Vertex = VertexDirection::into(value.1);
+        // Offsets are 0 or 1, so fit every component type.
+        let offset = |component: i32| T::from(component as i8);
 
         Vertex::new(
-            value.0.q + vert_dir.coord.x,
-            value.0.r + vert_dir.coord.y,
-            value.0.compute_s() + vert_dir.coord.z,
+            value.0.q + offset(vert_dir.coord.x),
+            value.0.r + offset(vert_dir.coord.y),
+            value.0.compute_s() + offset(vert_dir.coord.z),
         )
     }
 }
 
-impl From<Axial> for Vertex {
-    fn from(value: Axial) -> Self {
+impl<T: SignedInt> From<Axial<T>> for Vertex<T> {
+    fn from(value: Axial<T>) -> Self {
         Vertex::new(value.q, value.r, value.compute_s())
     }
 }
 
-impl From<Triangle> for Vertex {
-    fn from(value: Triangle) -> Self {
+impl<T> From<Triangle<T>> for Vertex<T> {
+    fn from(value: Triangle<T>) -> Self {
         Vertex { coord: value }
     }
 }
 
-impl Vertex {
+impl<T: Display> Display for Vertex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Vertex({}, {}, {})",
+            self.coord.x, self.coord.y, self.coord.z
+        )
+    }
+}
+
+/// Parses the form produced by [`Display`], e.g. `Vertex(1, 1, 0)`.
+impl<T: FromStr> FromStr for Vertex<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse::components(s, "Vertex")?;
+        Ok(Vertex {
+            coord: Triangle {
+                x: parse::component(x)?,
+                y: parse::component(y)?,
+                z: parse::component(z)?,
+            },
+        })
+    }
+}
+
+impl<T: SignedInt> Vertex<T> {
     ///Constructor for a vertex.
-    pub const fn new(q: i32, r: i32, s: i32) -> Vertex {
+    pub const fn new(q: T, r: T, s: T) -> Self {
         Vertex {
             coord: Triangle::new(q, r, s),
         }
     }
+
+    /// Convert to another component type that can represent every value of this one.
+    pub fn cast<U: From<T>>(self) -> Vertex<U> {
+        Vertex {
+            coord: self.coord.cast(),
+        }
+    }
+
+    /// Convert to another component type, checking each component fits.
+    ///
+    /// Produces [`None`] if a component is out of range of the new type.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::vertex::Vertex;
+    ///
+    /// assert_eq!(Vertex::new(1i32, 1, 0).try_cast::<i16>(), Some(Vertex::new(1i16, 1, 0)));
+    /// ```
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vertex<U>> {
+        self.coord.try_cast().map(|coord| Vertex { coord })
+    }
+
+    /// Get the vertex shared by three mutually neighboring hexes.
+    ///
+    /// Produces [`None`] if any two of the hexes are not neighbors.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::vertex::{Vertex, VertexDirection};
+    ///
+    /// let vertex = Vertex::between(axial!(0, 0), axial!(1, 0), axial!(0, 1));
+    /// assert_eq!(vertex, Some(axial!(0, 0).vertex(VertexDirection::DownRight)));
+    /// assert_eq!(Vertex::between(axial!(0, 0), axial!(1, 0), axial!(2, 0)), None);
+    /// ```
+    pub fn between(a: Axial<T>, b: Axial<T>, c: Axial<T>) -> Option<Self> {
+        match a.are_neighbors(&[b, c]) && b.are_neighbors(&[c]) {
+            true => a.shared_vert_three(b, c),
+            false => None,
+        }
+    }
+
     /// Get all 3 adjacent hexes to this vertex.
     ///
     /// # Example
@@ -145,18 +231,18 @@ impl Vertex {
     ///
     /// let coords = axial!(0,0).vertex(VertexDirection::Down).adjacent_hexes();
     /// ```
-    pub fn adjacent_hexes(&self) -> Option<[Axial; 3]> {
+    pub fn adjacent_hexes(&self) -> Option<[Axial<T>; 3]> {
         if let Some((coord, spin)) = self.try_to_axial() {
             match spin {
                 VertexSpin::Up => Some([
                     axial!(coord.q, coord.r),
-                    axial!(coord.q, coord.r - 1),
-                    axial!(coord.q + 1, coord.r - 1),
+                    axial!(coord.q, coord.r - T::ONE),
+                    axial!(coord.q + T::ONE, coord.r - T::ONE),
                 ]),
                 VertexSpin::Down => Some([
                     axial!(coord.q, coord.r),
-                    axial!(coord.q, coord.r + 1),
-                    axial!(coord.q - 1, coord.r + 1),
+                    axial!(coord.q, coord.r + T::ONE),
+                    axial!(coord.q - T::ONE, coord.r + T::ONE),
                 ]),
             }
         } else {
@@ -173,7 +259,7 @@ impl Vertex {
     ///
     /// let tri = Vertex::new(0, 0, 0).into_inner();
     /// ```
-    pub fn into_inner(self) -> Triangle {
+    pub fn into_inner(self) -> Triangle<T> {
         self.coord
     }
 
@@ -189,15 +275,16 @@ impl Vertex {
     /// assert!(Vertex::new(0, 0, 0).try_to_axial().is_none());
     /// assert_eq!(Vertex::new(1, 1, 0).try_to_axial().unwrap(), (axial!(0, 1), VertexSpin::Up));
     /// ```
-    pub fn try_to_axial(&self) -> Option<(Axial, VertexSpin)> {
+    pub fn try_to_axial(&self) -> Option<(Axial<T>, VertexSpin)> {
         if self.coord.is_tri_face() {
             match self.coord.orientation() {
                 TriOrientation::Up => {
-                    Some((axial!(self.coord.x - 1, self.coord.y), VertexSpin::Up))
-                }
-                TriOrientation::Down => {
-                    Some((axial!(self.coord.x, self.coord.y - 1), VertexSpin::Down))
+                    Some((axial!(self.coord.x - T::ONE, self.coord.y), VertexSpin::Up))
                 }
+                TriOrientation::Down => Some((
+                    axial!(self.coord.x, self.coord.y - T::ONE),
+                    VertexSpin::Down,
+                )),
             }
         } else {
             None
@@ -226,6 +313,137 @@ impl Vertex {
         }
     }
 
+    /// Compute a mask of the adjacent vertices matching a predicate.
+    ///
+    /// Bits are in the order of [`Vertex::adjacent_vertices`]. Produces [`None`] if the coordinate
+    /// is not a vertex, see [`Triangle::is_tri_face()`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::vertex::{Vertex, VertexDirection};
+    /// use gridava::hex::coordinate::{axial, Axial};
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// let mask = vertex.neighbor_mask(|adj| adj == axial!(0, 0).vertex(VertexDirection::UpRight));
+    /// assert_eq!(mask.unwrap().len(), 1);
+    /// ```
+    pub fn neighbor_mask<F>(&self, predicate: F) -> Option<TriMask>
+    where
+        F: FnMut(Self) -> bool,
+    {
+        self.adjacent_vertices()
+            .map(|vertices| TriMask::from_flags(vertices.map(predicate)))
+    }
+
+    /// Compute a mask of the adjacent hexes matching a predicate.
+    ///
+    /// Bits are in the order of [`Vertex::adjacent_hexes`], useful to pick corner tiles where
+    /// terrain meets. Produces [`None`] if the coordinate is not a vertex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::vertex::{Vertex, VertexDirection};
+    /// use gridava::hex::coordinate::{axial, Axial};
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// let mask = vertex.hex_mask(|coord| coord == axial!(0, 0));
+    /// assert_eq!(mask.unwrap().len(), 1);
+    /// ```
+    pub fn hex_mask<F>(&self, predicate: F) -> Option<TriMask>
+    where
+        F: FnMut(Axial<T>) -> bool,
+    {
+        self.adjacent_hexes()
+            .map(|hexes| TriMask::from_flags(hexes.map(predicate)))
+    }
+
+    /// Compute the L1 distance between two vertices in the component type.
+    ///
+    /// See [`Vertex::distance`] for `i32` vertices.
+    #[inline]
+    pub fn distance_t(self, b: Self) -> T {
+        self.coord.distance_t(b.coord)
+    }
+
+    /// Lazily produce the vertices within a number of steps along edges from this vertex.
+    ///
+    /// Produces nothing if this is not a vertex, see
+    /// [`Triangle::is_tri_face()`](crate::triangle::coordinate::Triangle::is_tri_face). Does not
+    /// allocate, see [`Vertex::range`] for a collected version.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::vertex::{Vertex, VertexDirection};
+    ///
+    /// // self and its 3 adjacent vertices
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// assert_eq!(vertex.range_iter(1).len(), 4);
+    /// assert_eq!(Vertex::new(0, 0, 0).range_iter(1).len(), 0);
+    /// ```
+    pub fn range_iter(self, dist: T) -> VertexRange<T> {
+        // Vertices are the faces of the triangle grid whose vertices are hex centers, so steps
+        // along edges are steps between faces.
+        let dist = match self.coord.is_tri_face() {
+            true => dist,
+            false => -T::ONE,
+        };
+        self.coord.range_iter(dist).map(Vertex::from)
+    }
+
+    /// Produce the vertices within a number of steps along edges from this vertex.
+    ///
+    /// See [`Vertex::range_iter`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn range(self, dist: T) -> Vec<Self> {
+        self.range_iter(dist).collect()
+    }
+
+    /// Produce the vertices exactly a number of steps along edges from this vertex.
+    ///
+    /// The vertices are ordered by their angle around this vertex, see [`Triangle::ring`]. A
+    /// distance of 0 produces self. Produces nothing if this is not a vertex.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// assert_eq!(vertex.ring(1).len(), 3);
+    /// assert_eq!(vertex.ring(2).len(), 6);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn ring(self, dist: T) -> Vec<Self> {
+        if !self.coord.is_tri_face() {
+            return Vec::new();
+        }
+
+        self.coord
+            .ring(dist, TriDirection::Left, Winding::Clockwise)
+            .into_iter()
+            .map(Vertex::from)
+            .collect()
+    }
+}
+
+impl Vertex {
+    /// Compute the L1 distance between two vertices.
+    ///
+    /// ```
+    /// use gridava::hex::vertex::{Vertex, VertexDirection};
+    /// use gridava::hex::coordinate::{axial, Axial};
+    ///
+    /// let vert_a = axial!(0,0).vertex(VertexDirection::Up);
+    /// let vert_b = axial!(1,0).vertex(VertexDirection::Up);
+    ///
+    /// let dist = vert_a.distance(vert_b);
+    /// ```
+    #[inline]
+    pub fn distance(self, b: Self) -> u32 {
+        self.coord.distance(b.coord)
+    }
+
     /// Generate the edges adjacent to this vertex.
     ///
     /// ```
@@ -253,26 +471,141 @@ impl Vertex {
         }
     }
 
-    /// Compute the L1 distance between two vertices.
+    /// Applies a transform matrix to this vertex.
+    ///
+    /// Scale has no meaning with a vertex so we do not scale here.
     ///
+    /// The order of applications is rotation then translation, see [`Axial::apply_transform`].
+    ///
+    /// # Example
     /// ```
-    /// use gridava::hex::vertex::{Vertex, VertexDirection};
-    /// use gridava::hex::coordinate::{axial, Axial};
+    /// use gridava::core::transform::{Transform, transform, Vector2D, vector2d};
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::vertex::VertexDirection;
     ///
-    /// let vert_a = axial!(0,0).vertex(VertexDirection::Up);
-    /// let vert_b = axial!(1,0).vertex(VertexDirection::Up);
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up);
+    /// assert_eq!(
+    ///     vertex.apply_transform(transform!(axial!(2, 0), 3)),
+    ///     axial!(2, 0).vertex(VertexDirection::Down)
+    /// );
+    /// ```
+    pub fn apply_transform(&self, transform: Transform<Axial>) -> Self {
+        let rotated = self.rotate(None, transform.rotation);
+
+        // Translating by a hex keeps the spin, see `From<Axial>`.
+        Vertex {
+            coord: rotated.coord + Vertex::from(transform.translation).coord,
+        }
+    }
+
+    /// Rotate a vertex.
     ///
-    /// let dist = vert_a.distance(vert_b);
+    /// `center` Optionally can specify a hex to rotate about. None will rotate about (0, 0).
+    ///
+    /// `rot_dir`: positive denotes CW, negative CCW, magnitude denotes how many 60 degree rotations.
+    ///
+    /// Coordinates of a hex center, see
+    /// [`Triangle::is_tri_face()`](crate::triangle::coordinate::Triangle::is_tri_face), are
+    /// rotated as that hex.
+    ///
+    /// # Example
     /// ```
-    #[inline]
-    pub fn distance(self, b: Self) -> u32 {
-        self.coord.distance(b.coord)
+    /// use gridava::hex::coordinate::{Axial, axial};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::Up).rotate(None, 1);
+    /// assert_eq!(vertex, axial!(0, 0).vertex(VertexDirection::UpRight));
+    /// ```
+    pub fn rotate(&self, center: Option<Axial>, rot_dir: i32) -> Self {
+        self.transform_about_hex(|hex| hex.rotate(center, rot_dir), |dir| dir + rot_dir)
+    }
+
+    /// Reflect a vertex.
+    ///
+    /// `center` Optionally can specify a hex to reflect about. None will reflect about (0, 0).
+    ///
+    /// See [`Axial::reflect`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::hex::coordinate::{Axes, Axial, axial};
+    /// use gridava::hex::vertex::VertexDirection;
+    ///
+    /// let vertex = axial!(0, 0).vertex(VertexDirection::UpRight).reflect(None, Axes::R);
+    /// assert_eq!(vertex, axial!(0, 0).vertex(VertexDirection::UpLeft));
+    /// ```
+    pub fn reflect(&self, center: Option<Axial>, axes: Axes) -> Self {
+        self.mirror(center, axes.into())
+    }
+
+    // Transform the vertex as its hex and its direction from that hex, which avoids the
+    // symmetry lattice for transforms about a hex. Coordinates of a hex center are transformed
+    // as that hex.
+    //
+    // `dir` maps the `i32` conversion of a `VertexDirection`.
+    pub(crate) fn transform_about_hex(
+        &self,
+        hex: impl Fn(Axial) -> Axial,
+        dir: impl Fn(i32) -> i32,
+    ) -> Self {
+        match self.try_to_axial() {
+            Some((coord, spin)) => {
+                let vert_dir = match spin {
+                    VertexSpin::Up => VertexDirection::Up,
+                    VertexSpin::Down => VertexDirection::Down,
+                };
+                hex(coord).vertex(VertexDirection::from(dir(vert_dir.into())))
+            }
+            None => Vertex::from(hex(axial!(self.coord.x, self.coord.y))),
+        }
+    }
+}
+
+/// Neighbors are the vertices at the other end of each adjacent edge, none if the coordinate
+/// is not a vertex.
+impl Neighbors for Vertex {
+    type NeighborIter = iter::Flatten<option::IntoIter<[Self; 3]>>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        self.adjacent_vertices().into_iter().flatten()
+    }
+}
+
+impl Distance for Vertex {
+    fn distance(&self, b: Self) -> u32 {
+        Vertex::distance(*self, b)
+    }
+}
+
+/// The vertices reached by walking along at most `range` edges, none if the coordinate is not a
+/// vertex.
+impl Range for Vertex {
+    type RangeIter = VertexRange;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        Vertex::range_iter(*self, range)
+    }
+}
+
+/// The vertices along the edges nearest the straight line between two vertices, none if either
+/// coordinate is not a vertex.
+impl Line for Vertex {
+    type LineIter =
+        iter::Map<iter::Flatten<option::IntoIter<TriangleLine>>, fn(Triangle) -> Vertex>;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        let line = match self.coord.is_tri_face() && b.coord.is_tri_face() {
+            true => Some(self.coord.line_iter(b.coord)),
+            false => None,
+        };
+        line.into_iter().flatten().map(Vertex::from)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hex::testing::assert_follows_hexes;
 
     #[test]
     fn from_axial() {
@@ -475,8 +808,171 @@ mod tests {
         assert_eq!(Vertex::from(VertexDirection::UpLeft), Vertex::new(0, 0, 1));
     }
 
+    #[test]
+    fn neighbor_mask() {
+        assert_eq!(Vertex::new(0, 0, 0).neighbor_mask(|_| true), None);
+        assert_eq!(Vertex::new(0, 0, 0).hex_mask(|_| true), None);
+
+        let vertex = axial!(0, 0).vertex(VertexDirection::Down);
+        assert_eq!(vertex.neighbor_mask(|_| true).unwrap().bits(), 0b111);
+
+        let adjacent = vertex.adjacent_vertices().unwrap();
+        let mask = vertex.neighbor_mask(|adj| adj == adjacent[1]).unwrap();
+        assert_eq!(mask.bits(), 0b010);
+
+        let mask = vertex
+            .hex_mask(|coord| coord == axial!(0, 0) || coord == axial!(-1, 1))
+            .unwrap();
+        assert_eq!(mask.bits(), 0b101);
+    }
+
+    #[test]
+    fn generic_width() {
+        // Beyond the range of i32.
+        let hex = axial!(1i64 << 40, -(1i64 << 40));
+        let vertex = hex.vertex(VertexDirection::Down);
+        assert_eq!(vertex.try_to_axial(), Some((hex, VertexSpin::Down)));
+        assert!(vertex.adjacent_hexes().unwrap().contains(&hex));
+        assert_eq!(vertex.hex_mask(|coord| coord == hex).unwrap().len(), 1);
+        assert_eq!(
+            Vertex::between(hex, hex + axial!(0, 1), hex + axial!(-1, 1)),
+            Some(vertex)
+        );
+        assert_eq!(vertex.distance_t(hex.vertex(VertexDirection::Up)), 3);
+        assert_eq!(vertex.range_iter(1).len(), 4);
+
+        let small = axial!(3i8, -1).vertex(VertexDirection::UpRight);
+        assert_eq!(
+            small.cast::<i32>(),
+            axial!(3, -1).vertex(VertexDirection::UpRight)
+        );
+    }
+
     #[test]
     fn default() {
         assert_eq!(Vertex::default(), Vertex::new(0, 0, 0));
     }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(format!("{}", Vertex::new(1, 1, 0)), "Vertex(1, 1, 0)");
+        assert_eq!("Vertex(1, 1, 0)".parse(), Ok(Vertex::new(1, 1, 0)));
+        assert_eq!(
+            "Vertex(1, 1)".parse::<Vertex>(),
+            Err(ParseCoordError::Format)
+        );
+    }
+
+    #[test]
+    fn between() {
+        for coord in axial!(-2, 1).range_iter(2) {
+            for vertex in coord.vertices() {
+                let [a, b, c] = vertex.adjacent_hexes().unwrap();
+                for [a, b, c] in [
+                    [a, b, c],
+                    [a, c, b],
+                    [b, a, c],
+                    [b, c, a],
+                    [c, a, b],
+                    [c, b, a],
+                ] {
+                    assert_eq!(Vertex::between(a, b, c), Some(vertex));
+                }
+                assert_eq!(Vertex::between(a, b, a), None);
+                assert_eq!(Vertex::between(a, a, a), None);
+            }
+        }
+
+        // Neighbors of the first hex that are not neighbors of each other.
+        assert_eq!(
+            Vertex::between(axial!(0, 0), axial!(1, 0), axial!(-1, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn transform() {
+        use crate::{
+            core::transform::{vector2d, Vector2D},
+            transform,
+        };
+
+        let center = axial!(-1, 2);
+        for coord in axial!(0, 0).range_iter(2) {
+            for vertex in coord.vertices() {
+                for rot_dir in -6..=6 {
+                    let rotated = vertex.rotate(Some(center), rot_dir);
+                    assert_follows_hexes(vertex, rotated, |hex| hex.rotate(Some(center), rot_dir));
+
+                    let transform = transform!(axial!(2, 3), rot_dir);
+                    assert_follows_hexes(vertex, vertex.apply_transform(transform), |hex| {
+                        hex.apply_transform(transform)
+                    });
+                }
+
+                for axes in [Axes::Q, Axes::R, Axes::S] {
+                    let reflected = vertex.reflect(Some(center), axes);
+                    assert_eq!(reflected.reflect(Some(center), axes), vertex);
+                    assert_follows_hexes(vertex, reflected, |hex| hex.reflect(Some(center), axes));
+                }
+            }
+
+            // Hex centers transform as the hex.
+            assert_eq!(
+                Vertex::from(coord).rotate(Some(center), 2),
+                Vertex::from(coord.rotate(Some(center), 2))
+            );
+            assert_eq!(
+                Vertex::from(coord).reflect(None, Axes::S),
+                Vertex::from(coord.reflect(None, Axes::S))
+            );
+        }
+
+        // Transforms about a hex are not limited by the symmetry lattice.
+        let far = axial!(i32::MAX / 4, 0);
+        let vertex = far.vertex(VertexDirection::Up);
+        assert_eq!(
+            vertex.rotate(Some(far), 1),
+            far.vertex(VertexDirection::UpRight)
+        );
+        assert_eq!(
+            vertex.reflect(Some(far), Axes::Q),
+            far.vertex(VertexDirection::DownRight)
+        );
+        let vertex = axial!(i32::MAX - 1, 0).vertex(VertexDirection::Down);
+        assert_follows_hexes(vertex, vertex.rotate(None, 3), |hex| hex.rotate(None, 3));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn range_ring() {
+        for dir in [VertexDirection::Up, VertexDirection::Down] {
+            let vertex = axial!(2, -1).vertex(dir);
+
+            let mut ring = vertex.ring(1);
+            let mut adjacent = vertex.adjacent_vertices().unwrap().to_vec();
+            ring.sort_by_key(|vertex| (vertex.coord.x, vertex.coord.y, vertex.coord.z));
+            adjacent.sort_by_key(|vertex| (vertex.coord.x, vertex.coord.y, vertex.coord.z));
+            assert_eq!(ring, adjacent);
+
+            // Each ring of the honeycomb grows by 3.
+            for dist in 0..5 {
+                let expected = match dist {
+                    0 => 1,
+                    _ => 3 * dist as usize,
+                };
+                assert_eq!(vertex.ring(dist).len(), expected);
+                assert!(vertex
+                    .ring(dist)
+                    .iter()
+                    .all(|other| other.distance(vertex) == dist as u32));
+            }
+            assert_eq!(vertex.range(3).len(), 1 + 3 + 6 + 9);
+            assert!(vertex.range(-1).is_empty());
+        }
+
+        // Hex centers are not vertices.
+        assert!(Vertex::new(1, -1, 0).range(2).is_empty());
+        assert!(Vertex::new(1, -1, 0).ring(0).is_empty());
+    }
 }
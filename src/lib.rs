@@ -21,17 +21,35 @@ mod lib {
     #[allow(clippy::excessive_precision)]
     pub const SQRT_3: f64 = 1.732050807568877293527446341505872367_f64;
 
-    pub use self::core::cmp::PartialEq;
+    pub use self::core::array;
+    pub use self::core::cmp::{Ordering, PartialEq, Reverse};
     pub use self::core::f64;
-    pub use self::core::fmt::{self, Display};
-    pub use self::core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+    pub use self::core::fmt::{self, Debug, Display};
+    pub use self::core::hash::Hash;
+    pub use self::core::iter::{self, FusedIterator};
+    pub use self::core::marker::PhantomData;
+    pub use self::core::ops::{
+        Add, AddAssign, BitAnd, BitOr, Div, Mul, MulAssign, Neg, Not, Rem, Sub, SubAssign,
+    };
+    pub use self::core::option;
+    pub use self::core::str::FromStr;
 
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    pub use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap};
     #[cfg(all(feature = "alloc", not(feature = "std")))]
     pub use alloc::vec::Vec;
 
+    #[cfg(feature = "std")]
+    pub use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
     #[cfg(feature = "std")]
     pub use std::{vec, vec::Vec};
 
+    // Formatting is only needed by tests so far.
+    #[cfg(all(test, feature = "alloc", not(feature = "std")))]
+    pub use alloc::format;
+    #[cfg(all(test, feature = "std"))]
+    pub use std::format;
+
     // Use serde if enabled.
     #[cfg(feature = "serde")]
     pub use serde::{Deserialize, Serialize};
@@ -41,7 +59,7 @@ mod lib {
 
     // Use libm when no_std
     #[cfg(not(feature = "std"))]
-    pub use libm::{atan2, fabs, round};
+    pub use libm::{atan2, ceil, fabs, floor, round};
 }
 pub mod core;
 pub mod hex;
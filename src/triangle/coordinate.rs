@@ -1,21 +1,37 @@
 //! Coordinate system for triangle based grids.
 
+use crate::core::autotile::TriMask;
+use crate::core::coordinate::{Distance, Line, Neighbors, Range};
+use crate::core::direction::{Direction, DirectionSet};
+use crate::core::int::SignedInt;
 use crate::core::misc::Axes3D;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::core::misc::Winding;
+use crate::core::parse::{self, ParseCoordError};
 use crate::lib::*;
-use either::{Either, Left, Right};
+
+mod checked;
+mod iter;
+mod wrap;
+pub use iter::{TriangleLine, TriangleRange};
 
 /// A coordinate for a triangular grid.
 ///
 /// Maps a coordinate to every triangular face and vertex on a triangular grid.
+///
+/// Components are `i32` by default, any [`SignedInt`] can be used instead. Arithmetic, neighbors,
+/// rotation, reflection, projection, ranges, rings and lines work for every component type, as
+/// does distance through [`Triangle::distance_t`]. Rounding from cartesian coordinates, wrapping
+/// and the grid traits, e.g. [`Range`], are `i32` only.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
-pub struct Triangle {
+pub struct Triangle<T = i32> {
     /// X coordinate
-    pub x: i32,
+    pub x: T,
     /// Y coordinate
-    pub y: i32,
+    pub y: T,
     /// Z coordinate
-    pub z: i32,
+    pub z: T,
 }
 
 /// Orientation of the tri-coordinate
@@ -33,10 +49,10 @@ pub enum TriOrientation {
     Down,
 }
 
-impl From<Triangle> for TriOrientation {
-    fn from(value: Triangle) -> Self {
+impl<T: SignedInt> From<Triangle<T>> for TriOrientation {
+    fn from(value: Triangle<T>) -> Self {
         match value.x + value.y + value.z {
-            sum if sum & 1 == 1 => TriOrientation::Down,
+            sum if sum % T::from(2) != T::ZERO => TriOrientation::Down,
             _ => TriOrientation::Up,
         }
     }
@@ -55,14 +71,88 @@ pub enum TriDirection {
     Base,
 }
 
+impl From<i32> for TriDirection {
+    fn from(value: i32) -> Self {
+        match value.rem_euclid(3) {
+            0 => TriDirection::Left,
+            1 => TriDirection::Right,
+            2 => TriDirection::Base,
+            _ => unreachable!(), // should never reach
+        }
+    }
+}
+
+impl From<TriDirection> for i32 {
+    fn from(value: TriDirection) -> Self {
+        match value {
+            TriDirection::Left => 0,
+            TriDirection::Right => 1,
+            TriDirection::Base => 2,
+        }
+    }
+}
+
+impl Direction for TriDirection {
+    const COUNT: u32 = 3;
+}
+
+/// A set of [`TriDirection`]s, see [`DirectionSet`].
+pub type TriDirectionSet = DirectionSet<TriDirection>;
+
+impl TriDirection {
+    /// Every direction, in the order of [`Triangle::neighbors`].
+    pub const ALL: [Self; 3] = [TriDirection::Left, TriDirection::Right, TriDirection::Base];
+
+    /// Iterate every direction, in the order of [`Triangle::neighbors`].
+    pub fn iter() -> impl ExactSizeIterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+}
+
 const UNIT_LENGTH: u32 = 1;
 
-impl Triangle {
+impl<T: SignedInt> Triangle<T> {
     /// Constructor for a Triangle.
-    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
 
+    /// Convert to another component type that can represent every value of this one.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let coord: Triangle<i64> = Triangle::new(1i16, 0, 0).cast();
+    /// assert_eq!(coord, Triangle::new(1i64, 0, 0));
+    /// ```
+    pub fn cast<U: From<T>>(self) -> Triangle<U> {
+        Triangle {
+            x: U::from(self.x),
+            y: U::from(self.y),
+            z: U::from(self.z),
+        }
+    }
+
+    /// Convert to another component type, checking each component fits.
+    ///
+    /// Produces [`None`] if a component is out of range of the new type.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// assert_eq!(Triangle::new(1i64, 0, 0).try_cast::<i8>(), Some(Triangle::new(1i8, 0, 0)));
+    /// assert_eq!(Triangle::new(300i64, 0, -299).try_cast::<i8>(), None);
+    /// ```
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Triangle<U>> {
+        Some(Triangle {
+            x: U::try_from(self.x).ok()?,
+            y: U::try_from(self.y).ok()?,
+            z: U::try_from(self.z).ok()?,
+        })
+    }
+
     /// Compute the z coordinate for a vertex coordinate
     ///
     /// An important distinction is made for this type of coordinate since for
@@ -76,10 +166,10 @@ impl Triangle {
 
     /// Solve for the third component of a tri-face coordinate.
     #[inline]
-    pub fn solve_coord(partial: (i32, i32), orientation: TriOrientation) -> i32 {
+    pub fn solve_coord(partial: (T, T), orientation: TriOrientation) -> T {
         match orientation {
-            TriOrientation::Up => 2 - partial.0 - partial.1,
-            TriOrientation::Down => 1 - partial.0 - partial.1,
+            TriOrientation::Up => T::from(2) - partial.0 - partial.1,
+            TriOrientation::Down => T::ONE - partial.0 - partial.1,
         }
     }
 
@@ -93,19 +183,40 @@ impl Triangle {
 
     /// Converts a tri coordinate to cartesian coordinates.
     pub fn to_cartesian(self, edge_length: u32) -> (f64, f64) {
-        let (x, y, z) = (self.x as f64, self.y as f64, self.z as f64);
+        let (x, y, z) = (self.x.to_f64(), self.y.to_f64(), self.z.to_f64());
         (
             (0.5 * x + -0.5 * z) * edge_length as f64,
             (-SQRT_3 / 6.0 * x + SQRT_3 / 3.0 * y - SQRT_3 / 6.0 * z) * edge_length as f64,
         )
     }
 
-    /// Converts from cartesian coordinates to the nearest tri face coordinate.
-    pub fn nearest_tri_face(cartesian: (f64, f64), edge_length: u32) -> Self {
+    // Converts from cartesian coordinates to any component type, see `Triangle::nearest_tri_face`.
+    #[cfg(feature = "std")]
+    fn nearest_face(cartesian: (f64, f64), edge_length: u32) -> Self {
+        Triangle::new(
+            T::from_f64(
+                ((1.0 * cartesian.0 - SQRT_3 / 3.0 * cartesian.1) / edge_length as f64).ceil(),
+            ),
+            T::from_f64(((SQRT_3 * 2.0 / 3.0 * cartesian.1) / edge_length as f64).floor()) + T::ONE,
+            T::from_f64(((-cartesian.0 - SQRT_3 / 3.0 * cartesian.1) / edge_length as f64).ceil()),
+        )
+    }
+
+    // Converts from cartesian coordinates to any component type, see `Triangle::nearest_tri_face`.
+    #[cfg(not(feature = "std"))]
+    fn nearest_face(cartesian: (f64, f64), edge_length: u32) -> Self {
+        use crate::lib::{ceil, floor};
+
         Triangle::new(
-            ((1.0 * cartesian.0 - SQRT_3 / 3.0 * cartesian.1) / edge_length as f64).ceil() as i32,
-            ((SQRT_3 * 2.0 / 3.0 * cartesian.1) / edge_length as f64).floor() as i32 + 1,
-            ((-1.0 * cartesian.0 - SQRT_3 / 3.0 * cartesian.1) / edge_length as f64).ceil() as i32,
+            T::from_f64(ceil(
+                (1.0 * cartesian.0 - SQRT_3 / 3.0 * cartesian.1) / edge_length as f64,
+            )),
+            T::from_f64(floor(
+                (SQRT_3 * 2.0 / 3.0 * cartesian.1) / edge_length as f64,
+            )) + T::ONE,
+            T::from_f64(ceil(
+                (-cartesian.0 - SQRT_3 / 3.0 * cartesian.1) / edge_length as f64,
+            )),
         )
     }
 
@@ -114,7 +225,7 @@ impl Triangle {
     /// Since the coordinates can map to faces or vertices it can be
     /// beneficial to check if it is a face or not.
     pub fn is_tri_face(self) -> bool {
-        (self.x + self.y + self.z) != 0
+        (self.x + self.y + self.z) != T::ZERO
     }
 
     /// Determines the orientation
@@ -128,11 +239,11 @@ impl Triangle {
     pub fn rotate(self, rot_dir: i32) -> Self {
         match rot_dir.rem_euclid(6) {
             0 => self,
-            1 => Triangle::new(1 - self.z, 1 - self.x, 1 - self.y),
+            1 => Triangle::new(T::ONE - self.z, T::ONE - self.x, T::ONE - self.y),
             2 => Triangle::new(self.y, self.z, self.x),
-            3 => Triangle::new(1 - self.x, 1 - self.y, 1 - self.z),
+            3 => Triangle::new(T::ONE - self.x, T::ONE - self.y, T::ONE - self.z),
             4 => Triangle::new(self.z, self.x, self.y),
-            5 => Triangle::new(1 - self.y, 1 - self.z, 1 - self.x),
+            5 => Triangle::new(T::ONE - self.y, T::ONE - self.z, T::ONE - self.x),
             _ => unreachable!(), // should never reach
         }
     }
@@ -153,62 +264,68 @@ impl Triangle {
 
     /// Reflect a tri across the cartesian y-axis
     pub fn reflect_y(self) -> Self {
-        Triangle::new(1 - self.z, 1 - self.y, 1 - self.x)
+        Triangle::new(T::ONE - self.z, T::ONE - self.y, T::ONE - self.x)
     }
 
     /// Projects a coordinate onto the line along the x-axis at x
-    pub fn projection_x(self, x: i32) -> Self {
+    pub fn projection_x(self, x: T) -> Self {
         if self.x == x {
             // We are already on the axis
             self
         } else {
             let dx = self.x - x;
-            let sign = dx.is_negative();
+            let sign = dx < T::ZERO;
             let ori = self.orientation() == TriOrientation::Up;
-            let offset = (sign == ori) as i32;
+            let offset = T::from((sign == ori) as i8);
 
-            const PROJECTION_LUT: [Triangle; 2] =
-                [Triangle::new(0, 1, 1), Triangle::new(0, -1, -1)];
+            let projection_lut = [
+                Triangle::new(T::ZERO, T::ONE, T::ONE),
+                Triangle::new(T::ZERO, -T::ONE, -T::ONE),
+            ];
             Triangle::new(x, self.y, self.z)
-                + (PROJECTION_LUT[sign as usize] * ((dx.abs() + offset) / 2))
+                + (projection_lut[sign as usize] * ((dx.abs() + offset) / T::from(2)))
         }
     }
 
     /// Projects a coordinate onto the line along the y-axis at y
-    pub fn projection_y(self, y: i32) -> Self {
+    pub fn projection_y(self, y: T) -> Self {
         if self.y == y {
             // We are already on the axis
             self
         } else {
             let dy = self.y - y;
-            let sign = dy.is_negative();
+            let sign = dy < T::ZERO;
             let ori = self.orientation() == TriOrientation::Up;
-            let offset = (sign == ori) as i32;
+            let offset = T::from((sign == ori) as i8);
 
             // The two coordinates are more than 1 lane apart
-            const PROJECTION_LUT: [Triangle; 2] =
-                [Triangle::new(1, 0, 1), Triangle::new(-1, 0, -1)];
+            let projection_lut = [
+                Triangle::new(T::ONE, T::ZERO, T::ONE),
+                Triangle::new(-T::ONE, T::ZERO, -T::ONE),
+            ];
             Triangle::new(self.x, y, self.z)
-                + (PROJECTION_LUT[sign as usize] * ((dy.abs() + offset) / 2))
+                + (projection_lut[sign as usize] * ((dy.abs() + offset) / T::from(2)))
         }
     }
 
     /// Projects a coordinate onto the line along the z axis at z
-    pub fn projection_z(self, z: i32) -> Self {
+    pub fn projection_z(self, z: T) -> Self {
         if self.z == z {
             // We are already on the axis
             self
         } else {
             let dz = self.z - z;
-            let sign = dz.is_negative();
+            let sign = dz < T::ZERO;
             let ori = self.orientation() == TriOrientation::Up;
-            let offset = (sign == ori) as i32;
+            let offset = T::from((sign == ori) as i8);
 
             // The two coordinates are more than 1 lane apart
-            const PROJECTION_LUT: [Triangle; 2] =
-                [Triangle::new(1, 1, 0), Triangle::new(-1, -1, 0)];
+            let projection_lut = [
+                Triangle::new(T::ONE, T::ONE, T::ZERO),
+                Triangle::new(-T::ONE, -T::ONE, T::ZERO),
+            ];
             Triangle::new(self.x, self.y, z)
-                + (PROJECTION_LUT[sign as usize] * ((dz.abs() + offset) / 2))
+                + (projection_lut[sign as usize] * ((dz.abs() + offset) / T::from(2)))
         }
     }
 
@@ -228,11 +345,11 @@ impl Triangle {
     /// Outputs degrees from positive x-axis to the target b.
     /// The range of output is `0.0..360.0`
     #[cfg(not(feature = "std"))]
-    pub fn direction(&self, b: Self) -> f64 {
+    pub fn direction(self, b: Self) -> f64 {
         use crate::lib::atan2;
         // direction to b from the pov of self
-        let (x, y) = (b - self).to_cartesian();
-        atan2(-y, -x).to_degrees() + 180.0
+        let (x, y) = (b - self).to_cartesian(UNIT_LENGTH);
+        -atan2(y, -x).to_degrees() + 180.0
     }
 
     /// Linear interpolation between two tri faces
@@ -246,45 +363,7 @@ impl Triangle {
         let x = crate::core::misc::lerp(self_x, b_x, t);
         let y = crate::core::misc::lerp(self_y, b_y, t);
 
-        Self::nearest_tri_face((x, y), UNIT_LENGTH)
-    }
-
-    /// Computes the intersection of two coordinates.
-    fn intersection(self, b: Self) -> ([Triangle; 2], [Triangle; 2]) {
-        let slope = (self.x - b.x) / (self.y - b.y);
-
-        match slope.is_negative() || slope == 0 {
-            // Negative slope
-            true => {
-                let (x, z) = match self.x < b.x {
-                    true => (b.x, self.z),
-                    false => (self.x, b.z),
-                };
-
-                (
-                    [
-                        Triangle::new(x, Self::solve_coord((x, z), TriOrientation::Down), z),
-                        Triangle::new(x, Self::solve_coord((x, z), TriOrientation::Up), z),
-                    ],
-                    [Triangle::new(-1, 1, 1), Triangle::new(-1, -1, 1)],
-                )
-            }
-            // Positive slope
-            false => {
-                let (x, y) = match self.x < b.x {
-                    true => (b.x, self.y),
-                    false => (self.x, b.y),
-                };
-
-                (
-                    [
-                        Triangle::new(x, y, Self::solve_coord((x, y), TriOrientation::Up)),
-                        Triangle::new(x, y, Self::solve_coord((x, y), TriOrientation::Down)),
-                    ],
-                    [Triangle::new(-1, 1, -1), Triangle::new(-1, 1, 1)],
-                )
-            }
-        }
+        Self::nearest_face((x, y), UNIT_LENGTH)
     }
 
     /// Determines which axis, if any, two coordinates share.
@@ -300,149 +379,24 @@ impl Triangle {
         }
     }
 
-    // Produces an iterator forming a line between self and b that is as close to the cartesian
-    // line as possible, in other words, smooth.
-    fn sub_line(
-        self,
-        b: Self,
-    ) -> Either<impl DoubleEndedIterator<Item = Self>, impl DoubleEndedIterator<Item = Self>> {
-        let (intersected, offsets) = self.intersection(b);
-        let ds0 = self.distance(intersected[0]);
-        let ds1 = self.distance(intersected[1]);
-
-        if ds0 < ds1 {
-            Left(
-                self.line_along_axis(
-                    intersected[0],
-                    self.shared_axis(intersected[0]).unwrap(),
-                    false,
-                )
-                .chain(
-                    intersected
-                        .into_iter()
-                        .enumerate()
-                        .map(move |(i, v)| v + offsets[i])
-                        .rev(),
-                )
-                .chain(
-                    b.line_along_axis(
-                        intersected[1],
-                        b.shared_axis(intersected[1]).unwrap(),
-                        false,
-                    )
-                    .rev(),
-                ),
-            )
-        } else {
-            Right(
-                self.line_along_axis(
-                    intersected[1],
-                    self.shared_axis(intersected[1]).unwrap(),
-                    false,
-                )
-                .chain(
-                    intersected
-                        .into_iter()
-                        .enumerate()
-                        .map(move |(i, v)| v + offsets[i]),
-                )
-                .chain(
-                    b.line_along_axis(
-                        intersected[0],
-                        b.shared_axis(intersected[0]).unwrap(),
-                        false,
-                    )
-                    .rev(),
-                ),
-            )
-        }
-    }
-
-    /// Computes a smooth line from any coordinate to another.
+    /// Lazily produces a line from self to b.
     ///
-    /// A step size of about 8 is good for most cases having a good tradeoff of performance and
-    /// appearance.
-    // Step size should be a power of 2, then we can bit shift divide
-    #[cfg(any(feature = "std", feature = "alloc"))]
-    fn smooth_line(self, b: Self, step_size: u32) -> Vec<Self> {
-        // Slice up line into small lines based on step size
-        let dist = self.distance(b);
-        let num_subdivisions = dist / step_size;
-
-        let mut endpoints = (0..num_subdivisions)
-            .map(|i| self.lerp(b, (step_size as f64 + (step_size * i) as f64) / dist as f64))
-            .collect::<Vec<_>>();
-
-        // Since the dist could be a number not evenly divisible by STEP_SIZE we need to check if
-        // we need to append b.
-        if (dist % step_size) > 0 {
-            endpoints.push(b);
-        }
-
-        // Traverse the endpoints array to smooth out the sublines generated.
-        let mut start = self;
-        (0..endpoints.len())
-            .flat_map(|i| {
-                let (a, b) = (start, endpoints[i]);
-                start = endpoints[i];
-                if let Some(axis) = a.shared_axis(b) {
-                    Left(a.line_along_axis(b, axis, true))
-                } else {
-                    Right(a.sub_line(b))
-                }
-            })
-            .collect()
-    }
-
-    /// Creates an iterator that forms a line along an axis of two points.
-    fn line_along_axis(
-        self,
-        b: Self,
-        axis: Axes3D,
-        inclusive: bool,
-    ) -> impl DoubleEndedIterator<Item = Self> {
-        let dist = self.distance(b);
-        let range = 0..dist - (!inclusive) as u32;
-        let is_start_down = match self.orientation() {
-            TriOrientation::Up => false,
-            TriOrientation::Down => true,
-        };
-        // Order of index is TriOrientation::Up == 0 TriOrientation::Down == 1
-        let step = match axis {
-            Axes3D::X => {
-                if (self.y < b.y) && (self.z > b.z) {
-                    [Triangle::new(0, 0, -1), Triangle::new(0, 1, 0)]
-                } else {
-                    [Triangle::new(0, -1, 0), Triangle::new(0, 0, 1)]
-                }
-            }
-            Axes3D::Y => {
-                if (self.x < b.x) && (self.z > b.z) {
-                    [Triangle::new(0, 0, -1), Triangle::new(1, 0, 0)]
-                } else {
-                    [Triangle::new(-1, 0, 0), Triangle::new(0, 0, 1)]
-                }
-            }
-            Axes3D::Z => {
-                if (self.x < b.x) && (self.y > b.y) {
-                    [Triangle::new(0, -1, 0), Triangle::new(1, 0, 0)]
-                } else {
-                    [Triangle::new(-1, 0, 0), Triangle::new(0, 1, 0)]
-                }
-            }
-        };
-        [self].into_iter().chain(range.map(move |i| {
-            let dbl_step = step[0] + step[1];
-            if i & 1 == 1 {
-                // Is odd
-                // Doing right shift by 1 to divide by 2
-                dbl_step * ((i + 1) >> 1) as i32 + self
-            } else {
-                // Is even
-                // Doing right shift by 1 to divide by 2
-                dbl_step * (i >> 1) as i32 + self + step[(is_start_down ^ ((i & 1) != 0)) as usize]
-            }
-        }))
+    /// The line follows the faces crossed by the cartesian segment between the two face
+    /// centers, so a -> b is always the reverse of b -> a. Yields exactly the distance of
+    /// self -> b + 1 faces without allocating.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let a = Triangle::new(-1, 0, 2);
+    /// let b = Triangle::new(2, 1, -1);
+    /// let mut line = a.line_iter(b);
+    /// assert_eq!(line.len(), a.distance(b) as usize + 1);
+    /// assert_eq!(line.last(), Some(b));
+    /// ```
+    pub fn line_iter(self, b: Self) -> TriangleLine<T> {
+        TriangleLine::new(self, b)
     }
 
     /// Produces a line from self to b
@@ -451,46 +405,144 @@ impl Triangle {
     /// will also equal the distance of self -> b + 1.
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn line(self, b: Self) -> Vec<Self> {
-        if self == b {
-            vec![self]
-        } else if let Some(axis) = self.shared_axis(b) {
-            self.line_along_axis(b, axis, true).collect()
-        } else {
-            self.smooth_line(b, 8)
-        }
+        self.line_iter(b).collect()
+    }
+
+    /// Lazily produce the coordinates within a set distance from this coordinate
+    ///
+    /// Does not allocate, see [`Triangle::range`] for a collected version.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// // self and its 3 neighbors
+    /// assert_eq!(Triangle::new(0, 1, 0).range_iter(1).len(), 4);
+    /// ```
+    pub fn range_iter(self, dist: T) -> TriangleRange<T> {
+        TriangleRange::new(self, dist)
     }
 
     /// Produce the coordinates within a set distance from this coordinate
     #[cfg(any(feature = "std", feature = "alloc"))]
-    pub fn range(self, dist: i32) -> Vec<Self> {
-        let mut ret = Vec::with_capacity((dist.pow(2) + 2 * dist + 1) as usize);
-        for dx in -dist..=dist {
-            for dy in (-dist - dx).max(-dist)..=(dist - dx).min(dist) {
-                let dz0 = 1 - (self.x + self.y + self.z + dx + dy);
-
-                if dx.abs() + dy.abs() + dz0.abs() <= dist {
-                    ret.push(self + Triangle::new(dx, dy, dz0));
-                }
+    pub fn range(self, dist: T) -> Vec<Self> {
+        self.range_iter(dist).collect()
+    }
+
+    /// Produce the coordinates at exactly a set distance from this coordinate
+    ///
+    /// The coordinates are ordered by their angle around self. The ring begins at the coordinate
+    /// nearest the `start` neighbor's direction and sweeps according to `winding`. A distance of 0
+    /// produces self.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn ring(self, dist: T, start: TriDirection, winding: Winding) -> Vec<Self> {
+        let mut ret = Vec::new();
+        if dist <= T::ZERO {
+            if dist == T::ZERO {
+                ret.push(self);
+            }
+            return ret;
+        }
 
-                let dz1 = dz0 + 1;
-                if dx.abs() + dy.abs() + dz1.abs() <= dist {
-                    ret.push(self + Triangle::new(dx, dy, dz1));
+        // Offsets from an up triangle at a distance have half the distance, rounded up, in their
+        // negative components and the rest in their positive ones. They form a hexagon whose sides
+        // alternate between those lengths, with corners such as (-neg, pos, 0). Down triangles
+        // mirror this through self.
+        let (neg, pos) = ((dist + T::ONE) / T::from(2), dist / T::from(2));
+        let (sign, axis): (i8, usize) = match (self.orientation(), start) {
+            (TriOrientation::Up, TriDirection::Left) => (1, 0),
+            (TriOrientation::Up, TriDirection::Base) => (1, 1),
+            (TriOrientation::Up, TriDirection::Right) => (1, 2),
+            (TriOrientation::Down, TriDirection::Left) => (-1, 2),
+            (TriOrientation::Down, TriDirection::Base) => (-1, 1),
+            (TriOrientation::Down, TriDirection::Right) => (-1, 0),
+        };
+
+        // Walk the hexagon counter clockwise from the corner (-neg, pos, 0), with the first local
+        // component along the start neighbor. Cycling components rotates by 120°.
+        let sign = T::from(sign);
+        let sides: [([i8; 3], T); 6] = [
+            ([0, -1, 1], pos),
+            ([1, -1, 0], neg),
+            ([1, 0, -1], pos),
+            ([0, 1, -1], neg),
+            ([-1, 1, 0], pos),
+            ([-1, 0, 1], neg),
+        ];
+        let mut local = [-neg, pos, T::ZERO];
+        let mut walk = Vec::with_capacity(
+            usize::try_from(dist.to_i128().saturating_mul(3)).unwrap_or(usize::MAX),
+        );
+        for (step, len) in sides {
+            let step = step.map(T::from);
+            let mut taken = T::ZERO;
+            while taken < len {
+                let mut offset = [T::ZERO; 3];
+                for (i, component) in local.iter().enumerate() {
+                    offset[(axis + i) % 3] = sign * *component;
                 }
+                walk.push(Triangle::new(
+                    self.x + offset[0],
+                    self.y + offset[1],
+                    self.z + offset[2],
+                ));
+                local = [local[0] + step[0], local[1] + step[1], local[2] + step[2]];
+                taken += T::ONE;
             }
         }
+
+        // The start direction crosses the middle of the first side.
+        let len = walk.len();
+        // pos is at most a third of the walk, so fits a usize.
+        let pos = usize::try_from(pos.to_i128()).unwrap_or(usize::MAX);
+        ret.reserve(len);
+        match winding {
+            Winding::Clockwise => {
+                let first = pos / 2;
+                ret.extend((0..len).map(|i| walk[(first + len - i) % len]));
+            }
+            Winding::CounterClockwise => {
+                let first = pos.div_ceil(2);
+                ret.extend((0..len).map(|i| walk[(first + i) % len]));
+            }
+        }
+        ret
+    }
+
+    /// Produce the coordinates within a set distance, ordered from self outward
+    ///
+    /// Each ring is produced as in [`Triangle::ring`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn spiral(self, dist: T, start: TriDirection, winding: Winding) -> Vec<Self> {
+        let mut ret = Vec::new();
+        let mut ring = T::ZERO;
+        while ring <= dist {
+            ret.extend(self.ring(ring, start, winding));
+            ring += T::ONE;
+        }
         ret
     }
 
     /// Generate a neighbor coordinate
     pub fn neighbor(self, direction: TriDirection) -> Self {
         match (direction, self.orientation()) {
-            (TriDirection::Left, TriOrientation::Up) => Triangle::new(self.x - 1, self.y, self.z),
-            (TriDirection::Base, TriOrientation::Up) => Triangle::new(self.x, self.y - 1, self.z),
-            (TriDirection::Right, TriOrientation::Up) => Triangle::new(self.x, self.y, self.z - 1),
-            (TriDirection::Left, TriOrientation::Down) => Triangle::new(self.x, self.y, self.z + 1),
-            (TriDirection::Base, TriOrientation::Down) => Triangle::new(self.x, self.y + 1, self.z),
+            (TriDirection::Left, TriOrientation::Up) => {
+                Triangle::new(self.x - T::ONE, self.y, self.z)
+            }
+            (TriDirection::Base, TriOrientation::Up) => {
+                Triangle::new(self.x, self.y - T::ONE, self.z)
+            }
+            (TriDirection::Right, TriOrientation::Up) => {
+                Triangle::new(self.x, self.y, self.z - T::ONE)
+            }
+            (TriDirection::Left, TriOrientation::Down) => {
+                Triangle::new(self.x, self.y, self.z + T::ONE)
+            }
+            (TriDirection::Base, TriOrientation::Down) => {
+                Triangle::new(self.x, self.y + T::ONE, self.z)
+            }
             (TriDirection::Right, TriOrientation::Down) => {
-                Triangle::new(self.x + 1, self.y, self.z)
+                Triangle::new(self.x + T::ONE, self.y, self.z)
             }
         }
     }
@@ -516,14 +568,80 @@ impl Triangle {
         true
     }
 
+    /// Compute a mask of the neighbors matching a predicate.
+    ///
+    /// Bit `i` of the mask is set if the neighbor in the `i`th [`TriDirection`], in the order
+    /// `Left`, `Right`, `Base`, matches. Rotating the mask forward rotates up-facing triangles
+    /// clockwise and down-facing triangles counter-clockwise.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::{Triangle, TriDirection};
+    ///
+    /// let coord = Triangle::new(0, 1, 0);
+    /// let base = coord.neighbor(TriDirection::Base);
+    /// assert_eq!(coord.neighbor_mask(|neighbor| neighbor == base).bits(), 0b100);
+    /// ```
+    pub fn neighbor_mask<F>(self, predicate: F) -> TriMask
+    where
+        F: FnMut(Self) -> bool,
+    {
+        TriMask::from_flags(self.neighbors().map(predicate))
+    }
+
+    /// Computes L1 distance between coordinates in the component type.
+    ///
+    /// See [`Triangle::distance`] for `i32` coordinates.
+    pub fn distance_t(self, b: Self) -> T {
+        let dt = self - b;
+        dt.x.abs() + dt.y.abs() + dt.z.abs()
+    }
+}
+
+impl Triangle {
     /// Computes L1 distance between coordinates
     pub fn distance(self, b: Self) -> u32 {
         let dt = self - b;
         dt.x.unsigned_abs() + dt.y.unsigned_abs() + dt.z.unsigned_abs()
     }
+
+    /// Converts from cartesian coordinates to the nearest tri face coordinate.
+    pub fn nearest_tri_face(cartesian: (f64, f64), edge_length: u32) -> Self {
+        Self::nearest_face(cartesian, edge_length)
+    }
+}
+
+impl Neighbors for Triangle {
+    type NeighborIter = array::IntoIter<Self, 3>;
+
+    fn neighbors(&self) -> Self::NeighborIter {
+        Triangle::neighbors(*self).into_iter()
+    }
+}
+
+impl Distance for Triangle {
+    fn distance(&self, b: Self) -> u32 {
+        Triangle::distance(*self, b)
+    }
 }
 
-impl Add for Triangle {
+impl Range for Triangle {
+    type RangeIter = TriangleRange;
+
+    fn range_iter(&self, range: i32) -> Self::RangeIter {
+        Triangle::range_iter(*self, range)
+    }
+}
+
+impl Line for Triangle {
+    type LineIter = TriangleLine;
+
+    fn line_iter(&self, b: Self) -> Self::LineIter {
+        Triangle::line_iter(*self, b)
+    }
+}
+
+impl<T: SignedInt> Add for Triangle<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -535,7 +653,7 @@ impl Add for Triangle {
     }
 }
 
-impl Sub for Triangle {
+impl<T: SignedInt> Sub for Triangle<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -547,9 +665,9 @@ impl Sub for Triangle {
     }
 }
 
-impl<T> Mul<T> for Triangle
+impl<I, T> Mul<T> for Triangle<I>
 where
-    i32: Mul<T, Output = i32>,
+    I: Mul<T, Output = I>,
     T: Copy,
 {
     type Output = Self;
@@ -563,12 +681,26 @@ where
     }
 }
 
-impl Display for Triangle {
+impl<T: Display> Display for Triangle<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Tri-coordinate({}, {}, {})", self.x, self.y, self.z)
     }
 }
 
+/// Parses the form produced by [`Display`], e.g. `Tri-coordinate(1, 0, 0)`.
+impl<T: FromStr> FromStr for Triangle<T> {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse::components(s, "Tri-coordinate")?;
+        Ok(Triangle {
+            x: parse::component(x)?,
+            y: parse::component(y)?,
+            z: parse::component(z)?,
+        })
+    }
+}
+
 impl Display for TriOrientation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -788,6 +920,18 @@ mod tests {
         test!(Triangle::new(2, 1, -1), 3, Triangle::new(0, -1, 3));
     }
 
+    #[test]
+    fn tri_direction() {
+        for (index, dir) in TriDirection::iter().enumerate() {
+            assert_eq!(i32::from(dir), index as i32);
+            assert_eq!(TriDirection::from(index as i32 - 3), dir);
+            assert_eq!(
+                Triangle::new(0, 1, 0).neighbors()[index],
+                Triangle::new(0, 1, 0).neighbor(dir)
+            );
+        }
+    }
+
     #[test]
     fn direction() {
         assert_f64_near!(
@@ -1030,6 +1174,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ring() {
+        assert_eq!(
+            Triangle::new(0, 1, 0).ring(0, TriDirection::Left, Winding::Clockwise),
+            vec![Triangle::new(0, 1, 0)]
+        );
+        assert!(Triangle::new(0, 1, 0)
+            .ring(-1, TriDirection::Left, Winding::Clockwise)
+            .is_empty());
+
+        // The first ring is the neighbors.
+        assert_eq!(
+            Triangle::new(0, 1, 0).ring(1, TriDirection::Left, Winding::Clockwise),
+            vec![
+                Triangle::new(0, 1, 1),
+                Triangle::new(0, 2, 0),
+                Triangle::new(1, 1, 0),
+            ]
+        );
+        assert_eq!(
+            Triangle::new(0, 1, 0).ring(1, TriDirection::Left, Winding::CounterClockwise),
+            vec![
+                Triangle::new(0, 1, 1),
+                Triangle::new(1, 1, 0),
+                Triangle::new(0, 2, 0),
+            ]
+        );
+        assert_eq!(
+            Triangle::new(0, 1, 0).ring(1, TriDirection::Right, Winding::Clockwise),
+            vec![
+                Triangle::new(1, 1, 0),
+                Triangle::new(0, 1, 1),
+                Triangle::new(0, 2, 0),
+            ]
+        );
+
+        // Starts on the start neighbor's direction when a coordinate lies on it.
+        let center = Triangle::new(1, 0, 1);
+        for dist in [4, 5, 8] {
+            let ring = center.ring(dist, TriDirection::Left, Winding::Clockwise);
+            let along = center.direction(center.neighbor(TriDirection::Left));
+            assert!((center.direction(ring[0]) - along).abs() < 1e-9);
+        }
+
+        for winding in [Winding::Clockwise, Winding::CounterClockwise] {
+            for center in [Triangle::new(0, 1, 0), Triangle::new(1, 0, 0)] {
+                let ring = center.ring(3, TriDirection::Base, winding);
+                let expected = center
+                    .range(3)
+                    .into_iter()
+                    .filter(|c| center.distance(*c) == 3)
+                    .count();
+                assert_eq!(ring.len(), expected);
+                assert!(ring.iter().all(|c| center.distance(*c) == 3));
+            }
+        }
+    }
+
+    #[test]
+    fn spiral() {
+        let center = Triangle::new(1, 0, 0);
+        let spiral = center.spiral(3, TriDirection::Left, Winding::Clockwise);
+        let mut sorted = spiral.clone();
+        let mut range = center.range(3);
+        sorted.sort_by_key(|c| (c.x, c.y, c.z));
+        range.sort_by_key(|c| (c.x, c.y, c.z));
+        assert_eq!(sorted, range);
+
+        assert!(spiral
+            .windows(2)
+            .all(|w| center.distance(w[0]) <= center.distance(w[1])));
+    }
+
     #[test]
     fn are_neighbors() {
         assert!(Triangle::new(1, 0, 0).are_neighbors(&[
@@ -1039,4 +1256,88 @@ mod tests {
         ]));
         assert!(!Triangle::new(0, 0, 1).are_neighbors(&[Triangle::new(2, 0, 0)]));
     }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn generic_queries() {
+        // Every query agrees with the i32 version once widened.
+        let wide = |coord: Triangle| coord.cast::<i64>();
+        let center = Triangle::new(0, 1, 0);
+        for coord in center.range_iter(3) {
+            assert_eq!(
+                wide(coord).distance_t(wide(center)),
+                i64::from(coord.distance(center))
+            );
+            assert_eq!(wide(coord).orientation(), coord.orientation());
+            assert_eq!(
+                wide(coord).rotate_about(wide(center), 2),
+                wide(coord.rotate_about(center, 2))
+            );
+            assert_eq!(wide(coord).reflect_y(), wide(coord.reflect_y()));
+            assert_eq!(wide(coord).projection_x(2), wide(coord.projection_x(2)));
+            assert_eq!(wide(coord).neighbors(), coord.neighbors().map(wide));
+            assert_eq!(
+                wide(coord).line(wide(center)),
+                coord.line(center).into_iter().map(wide).collect::<Vec<_>>()
+            );
+        }
+        assert!(wide(center)
+            .range_iter(3)
+            .eq(center.range_iter(3).map(wide)));
+        assert!(wide(center)
+            .spiral(3, TriDirection::Base, Winding::CounterClockwise)
+            .into_iter()
+            .eq(center
+                .spiral(3, TriDirection::Base, Winding::CounterClockwise)
+                .into_iter()
+                .map(wide)));
+
+        // Beyond the range of i32.
+        let far = Triangle::new(1i64 << 40, 1 - (1i64 << 41), 1 << 40);
+        assert_eq!(far.orientation(), TriOrientation::Down);
+        assert_eq!(far.range_iter(1).len(), 4);
+        assert_eq!(far.ring(1, TriDirection::Left, Winding::Clockwise).len(), 3);
+        assert_eq!(far.line_iter(far.neighbor(TriDirection::Base)).len(), 2);
+        assert_eq!(Triangle::<i8>::new(0, 1, 0).range_iter(2).len(), 10);
+    }
+
+    #[test]
+    fn generic_width() {
+        let a = Triangle::new(i64::from(i32::MAX), 1, i64::from(i32::MIN));
+        let b = Triangle::<i64>::new(1, 0, 0);
+        assert_eq!((a + b - b) * 2, Triangle::new(a.x * 2, 2, a.z * 2));
+        assert_eq!((a * 2).try_cast::<i32>(), None);
+        assert!(a.try_cast::<i32>().is_some());
+        assert_eq!(b.try_cast::<i16>(), Some(Triangle::new(1, 0, 0)));
+        assert_eq!(
+            Triangle::new(1i8, 0, 0).cast::<i32>(),
+            Triangle::new(1, 0, 0)
+        );
+    }
+
+    #[test]
+    fn neighbor_mask() {
+        let coord = Triangle::new(1, 0, 0);
+        assert_eq!(coord.neighbor_mask(|_| false).bits(), 0);
+        assert_eq!(
+            coord
+                .neighbor_mask(|neighbor| neighbor == Triangle::new(2, 0, 0))
+                .bits(),
+            0b010
+        );
+        assert_eq!(
+            coord.neighbor_mask(|neighbor| neighbor.y == 0).bits(),
+            0b011
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let coord = Triangle::new(1, 0, -1);
+        assert_eq!(format!("{coord}").parse(), Ok(coord));
+        assert_eq!(
+            "Tri-coordinate(1, 0)".parse::<Triangle>(),
+            Err(ParseCoordError::Format)
+        );
+    }
 }
@@ -0,0 +1,314 @@
+//! Overflow checked and saturating coordinate arithmetic.
+//!
+//! The operators on [`Triangle`] wrap around in release builds, these variants either report the
+//! overflow or clamp to the bounds of the component type.
+
+use crate::core::int::SignedInt;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::lib::*;
+
+use super::{Triangle, TriangleLine, TriangleRange};
+
+impl<T: SignedInt> Triangle<T> {
+    /// Add two coordinates, [`None`] if a component overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let coord = Triangle::new(1, 0, 0);
+    /// assert_eq!(coord.checked_add(Triangle::new(0, 1, 0)), Some(Triangle::new(1, 1, 0)));
+    /// assert_eq!(Triangle::new(i32::MAX, 0, 0).checked_add(coord), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Triangle {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+        })
+    }
+
+    /// Subtract two coordinates, [`None`] if a component overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Triangle {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+            z: self.z.checked_sub(rhs.z)?,
+        })
+    }
+
+    /// Scale a coordinate, [`None`] if a component overflows.
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        Some(Triangle {
+            x: self.x.checked_mul(rhs)?,
+            y: self.y.checked_mul(rhs)?,
+            z: self.z.checked_mul(rhs)?,
+        })
+    }
+
+    /// Add two coordinates, clamping each component to the bounds of the component type.
+    ///
+    /// A clamped component changes the sum of the components, the result may then not be a
+    /// triangle face, see [`Triangle::is_tri_face`].
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Triangle {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+            z: self.z.saturating_add(rhs.z),
+        }
+    }
+
+    /// Subtract two coordinates, clamping each component to the bounds of the component type.
+    ///
+    /// See [`Triangle::saturating_add`].
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Triangle {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+            z: self.z.saturating_sub(rhs.z),
+        }
+    }
+
+    /// Scale a coordinate, clamping each component to the bounds of the component type.
+    ///
+    /// See [`Triangle::saturating_add`].
+    pub fn saturating_mul(self, rhs: T) -> Self {
+        Triangle {
+            x: self.x.saturating_mul(rhs),
+            y: self.y.saturating_mul(rhs),
+            z: self.z.saturating_mul(rhs),
+        }
+    }
+
+    /// Computes L1 distance between coordinates, [`None`] if it overflows.
+    ///
+    /// See [`Triangle::distance`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let a = Triangle::new(0, 0, 1);
+    /// assert_eq!(a.checked_distance(Triangle::new(1, 0, 1)), Some(1));
+    /// assert_eq!(a.checked_distance(Triangle::new(i32::MIN, 0, 1)), None);
+    /// ```
+    pub fn checked_distance(self, b: Self) -> Option<T> {
+        let dt = self.checked_sub(b)?;
+        dt.x.checked_abs()?
+            .checked_add(dt.y.checked_abs()?)?
+            .checked_add(dt.z.checked_abs()?)
+    }
+
+    /// Rotate about the origin, [`None`] if a component overflows.
+    ///
+    /// See [`Triangle::rotate`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let coord = Triangle::new(1, 0, 0);
+    /// assert_eq!(coord.checked_rotate(1), Some(Triangle::new(1, 0, 1)));
+    /// assert_eq!(Triangle::new(0, 0, i32::MIN).checked_rotate(1), None);
+    /// ```
+    pub fn checked_rotate(self, rot_dir: i32) -> Option<Self> {
+        // Odd rotations mirror each component about 1/2.
+        let flip = |component: T| T::ONE.checked_sub(component);
+
+        Some(match rot_dir.rem_euclid(6) {
+            0 => self,
+            1 => Triangle::new(flip(self.z)?, flip(self.x)?, flip(self.y)?),
+            2 => Triangle::new(self.y, self.z, self.x),
+            3 => Triangle::new(flip(self.x)?, flip(self.y)?, flip(self.z)?),
+            4 => Triangle::new(self.z, self.x, self.y),
+            5 => Triangle::new(flip(self.y)?, flip(self.z)?, flip(self.x)?),
+            _ => unreachable!(), // should never reach
+        })
+    }
+
+    /// Rotate about another coordinate, [`None`] if a component overflows.
+    ///
+    /// See [`Triangle::rotate_about`].
+    pub fn checked_rotate_about(self, about_b: Self, rot_dir: i32) -> Option<Self> {
+        about_b.checked_add(self.checked_sub(about_b)?.checked_rotate(rot_dir)?)
+    }
+
+    /// Lazily produce the coordinates within a set distance, [`None`] if a coordinate overflows.
+    ///
+    /// See [`Triangle::range_iter`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// assert_eq!(Triangle::new(0, 1, 0).checked_range_iter(1).unwrap().len(), 4);
+    /// assert!(Triangle::new(i32::MAX, 0, 1 - i32::MAX).checked_range_iter(1).is_none());
+    /// ```
+    pub fn checked_range_iter(self, dist: T) -> Option<TriangleRange<T>> {
+        if dist > T::ZERO {
+            // An offset of k along one axis leaves the other two to undo it, which takes another
+            // k steps less the change of the component sum. Sums of 1 and 2 are faces, so
+            // components reach up to (dist + 2 - sum) / 2 above and (dist + sum - 1) / 2 below.
+            let sum = self.x.to_i128() + self.y.to_i128() + self.z.to_i128();
+            let above = (dist.to_i128() + 2 - sum).div_euclid(2);
+            let below = (dist.to_i128() + sum - 1).div_euclid(2);
+
+            for component in [self.x, self.y, self.z] {
+                T::try_from_i128(component.to_i128() + above)?;
+                T::try_from_i128(component.to_i128() - below)?;
+            }
+        }
+        Some(self.range_iter(dist))
+    }
+
+    /// Produce the coordinates within a set distance, [`None`] if a coordinate overflows.
+    ///
+    /// See [`Triangle::range`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn checked_range(self, dist: T) -> Option<Vec<Self>> {
+        Some(self.checked_range_iter(dist)?.collect())
+    }
+
+    /// Lazily produce a line from self to b, [`None`] if the length of the line overflows.
+    ///
+    /// See [`Triangle::line_iter`].
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let a = Triangle::new(0, 1, 0);
+    /// assert_eq!(a.checked_line_iter(Triangle::new(1, 1, 0)).unwrap().len(), 2);
+    /// assert!(a.checked_line_iter(Triangle::new(i32::MIN, 1, i32::MAX)).is_none());
+    /// ```
+    pub fn checked_line_iter(self, b: Self) -> Option<TriangleLine<T>> {
+        // The line has one more coordinate than its length.
+        self.checked_distance(b)?.checked_add(T::ONE)?;
+        Some(self.line_iter(b))
+    }
+
+    /// Produce a line from self to b, [`None`] if the length of the line overflows.
+    ///
+    /// See [`Triangle::line`].
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn checked_line(self, b: Self) -> Option<Vec<Self>> {
+        Some(self.checked_line_iter(b)?.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_arithmetic() {
+        let a = Triangle::new(2, -1, 0);
+        let b = Triangle::new(-3, 0, 4);
+        assert_eq!(a.checked_add(b), Some(a + b));
+        assert_eq!(a.checked_sub(b), Some(a - b));
+        assert_eq!(a.checked_mul(-3), Some(a * -3));
+        assert_eq!(a.checked_distance(b), Some(a.distance_t(b)));
+
+        assert_eq!(Triangle::new(0, 0, i32::MIN).checked_sub(b), None);
+        assert_eq!(Triangle::new(0, 1 << 16, 0).checked_mul(1 << 16), None);
+        assert_eq!(
+            Triangle::new(100i8, 0, 0).checked_add(Triangle::new(28, 0, 0)),
+            None
+        );
+
+        // Each component fits but their sum does not.
+        let far = Triangle::new(i32::MAX, i32::MAX, -i32::MAX);
+        assert_eq!(far.checked_distance(Triangle::new(0, 0, 0)), None);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(
+            Triangle::new(i32::MAX, i32::MIN, 0).saturating_add(Triangle::new(1, -1, 1)),
+            Triangle::new(i32::MAX, i32::MIN, 1)
+        );
+        assert_eq!(
+            Triangle::new(-100i8, 0, 1).saturating_sub(Triangle::new(100, 0, 0)),
+            Triangle::new(i8::MIN, 0, 1)
+        );
+        assert_eq!(
+            Triangle::new(3, 1 << 30, 0).saturating_mul(4),
+            Triangle::new(12, i32::MAX, 0)
+        );
+    }
+
+    #[test]
+    fn checked_rotate() {
+        let center = Triangle::new(2, -1, 0);
+        for coord in center.range_iter(3) {
+            for rot_dir in -6..=6 {
+                assert_eq!(coord.checked_rotate(rot_dir), Some(coord.rotate(rot_dir)));
+                assert_eq!(
+                    coord.checked_rotate_about(center, rot_dir),
+                    Some(coord.rotate_about(center, rot_dir))
+                );
+            }
+        }
+
+        let min = Triangle::new(0, 0, i32::MIN);
+        assert_eq!(min.checked_rotate(2), Some(Triangle::new(0, i32::MIN, 0)));
+        assert_eq!(min.checked_rotate(1), None);
+        assert_eq!(min.checked_rotate(-1), None);
+        assert_eq!(
+            Triangle::new(i32::MAX, 0, 0).checked_rotate_about(Triangle::new(-1, 0, 0), 2),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_range() {
+        // Move x by an offset, keeping the sum of the components.
+        let shift = |coord: Triangle, dx: i32| coord + Triangle::new(dx, -(dx / 2), dx / 2 - dx);
+
+        // The bounds match the furthest faces of the range on both sides of each axis.
+        for center in [Triangle::new(0, 1, 0), Triangle::new(0, 1, 1)] {
+            for dist in 1..6 {
+                let range = center.checked_range_iter(dist).unwrap();
+                assert!(range.clone().eq(center.range_iter(dist)));
+
+                let max = range.clone().map(|coord| coord.x).max().unwrap();
+                let min = range.map(|coord| coord.x).min().unwrap();
+                for (edge, past) in [
+                    (i32::MAX - max, (i32::MAX - max).checked_add(1)),
+                    (i32::MIN - min, (i32::MIN - min).checked_sub(1)),
+                ] {
+                    assert!(shift(center, edge).checked_range_iter(dist).is_some());
+                    if let Some(past) = past {
+                        assert!(shift(center, past).checked_range_iter(dist).is_none());
+                    }
+                }
+            }
+        }
+
+        let far = Triangle::new(i32::MAX, i32::MIN, 3);
+        assert_eq!(far.checked_range_iter(0).unwrap().len(), 1);
+        assert_eq!(far.checked_range_iter(-1).unwrap().len(), 0);
+        // Faces only reach about half the distance along each axis.
+        assert!(Triangle::new(0, 1, 0)
+            .checked_range_iter(i32::MAX)
+            .is_some());
+        assert!(Triangle::new(1 << 30, 1, -(1 << 30))
+            .checked_range_iter(i32::MAX)
+            .is_none());
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn checked_line() {
+        let a = Triangle::new(-1, 0, 2);
+        let b = Triangle::new(2, 1, -1);
+        assert_eq!(a.checked_line(b), Some(a.line(b)));
+        assert_eq!(b.checked_line(Triangle::new(i32::MIN, 1, 1)), None);
+
+        // The distance fits but the number of coordinates, one more, does not.
+        let far = Triangle::new((1 << 30) - 1, 2 - (1 << 30), 1);
+        assert_eq!(far.distance_t(Triangle::new(0, 1, 0)), i32::MAX);
+        assert!(far.checked_line_iter(Triangle::new(0, 1, 1)).is_some());
+        assert!(far.checked_line_iter(Triangle::new(0, 1, 0)).is_none());
+    }
+}
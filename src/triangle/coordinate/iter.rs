@@ -0,0 +1,284 @@
+//! Lazy iterators over triangle coordinate queries.
+//!
+//! These iterators do not allocate and report an exact [`Iterator::size_hint`].
+
+use crate::lib::*;
+
+use super::{Triangle, UNIT_LENGTH};
+use crate::core::int::SignedInt;
+
+/// Iterator over every face within a distance, see [`Triangle::range_iter`].
+#[derive(Clone, Debug)]
+pub struct TriangleRange<T = i32> {
+    center: Triangle<T>,
+    // Offsets are tracked in i128 so stepping around the range never overflows, only the faces
+    // produced need to fit, see [`Triangle::checked_range_iter`].
+    sum: i128,
+    dist: i128,
+    dx: i128,
+    dy: i128,
+    // Next of the two z candidates to test for (dx, dy)
+    dz_offset: i128,
+    remaining: usize,
+}
+
+impl<T: SignedInt> TriangleRange<T> {
+    pub(super) fn new(center: Triangle<T>, dist: T) -> Self {
+        let dist = dist.to_i128();
+        let remaining = match dist.is_negative() {
+            true => 0,
+            // Each step outward adds 3 more faces than the previous one. A distance this large
+            // could never be collected, so the count saturates.
+            false => {
+                let dist = usize::try_from(dist).unwrap_or(usize::MAX);
+                (dist.saturating_mul(dist.saturating_add(1)) / 2)
+                    .saturating_mul(3)
+                    .saturating_add(1)
+            }
+        };
+
+        Self {
+            center,
+            sum: center.x.to_i128() + center.y.to_i128() + center.z.to_i128(),
+            dist,
+            dx: -dist,
+            // max(-dist - dx, -dist) with dx = -dist
+            dy: 0,
+            dz_offset: 0,
+            remaining,
+        }
+    }
+}
+
+impl<T: SignedInt> Iterator for TriangleRange<T> {
+    type Item = Triangle<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            if self.dz_offset > 1 {
+                self.dz_offset = 0;
+                self.dy += 1;
+            }
+
+            if self.dy > (self.dist - self.dx).min(self.dist) {
+                self.dx += 1;
+                self.dy = (-self.dist - self.dx).max(-self.dist);
+            }
+
+            let (dx, dy) = (self.dx, self.dy);
+            let dz = 1 - (self.sum + dx + dy) + self.dz_offset;
+            self.dz_offset += 1;
+
+            if dx.abs() + dy.abs() + dz.abs() <= self.dist {
+                self.remaining -= 1;
+                // Each offset is within the distance, so fits the component type.
+                let [dx, dy, dz] = [dx, dy, dz]
+                    .map(|offset| T::try_from_i128(offset).expect("offset within the distance"));
+                return Some(self.center + Triangle::new(dx, dy, dz));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: SignedInt> ExactSizeIterator for TriangleRange<T> {}
+impl<T: SignedInt> FusedIterator for TriangleRange<T> {}
+
+// Offset applied to both endpoints of a line so the segment never passes exactly through a
+// vertex. Shared by a -> b and b -> a so both traverse the same faces.
+const NUDGE: (f64, f64) = (1e-6, -2e-6);
+
+// Continuous lane position of a cartesian point along each axis, the inverse of
+// `Triangle::nearest_tri_face` before rounding.
+fn lanes((x, y): (f64, f64)) -> [f64; 3] {
+    [
+        x - SQRT_3 / 3.0 * y,
+        SQRT_3 * 2.0 / 3.0 * y,
+        -x - SQRT_3 / 3.0 * y,
+    ]
+}
+
+/// Iterator over the faces forming a line, see [`Triangle::line_iter`].
+#[derive(Clone, Debug)]
+pub struct TriangleLine<T = i32> {
+    current: Triangle<T>,
+    started: bool,
+    start: [i128; 3],
+    origin: [f64; 3],
+    delta: [f64; 3],
+    step: [T; 3],
+    crossed: [u128; 3],
+    crossings: [u128; 3],
+}
+
+impl<T: SignedInt> TriangleLine<T> {
+    pub(super) fn new(a: Triangle<T>, b: Triangle<T>) -> Self {
+        let nudge = |(x, y): (f64, f64)| (x + NUDGE.0, y + NUDGE.1);
+        let origin = lanes(nudge(a.to_cartesian(UNIT_LENGTH)));
+        let end = lanes(nudge(b.to_cartesian(UNIT_LENGTH)));
+
+        let start = [a.x, a.y, a.z].map(T::to_i128);
+        let diff = [b.x, b.y, b.z].map(T::to_i128);
+        let diff = [0, 1, 2].map(|i| diff[i] - start[i]);
+
+        Self {
+            current: a,
+            started: false,
+            start,
+            origin,
+            delta: [0, 1, 2].map(|i| end[i] - origin[i]),
+            step: diff.map(|diff| T::from(diff.signum() as i8)),
+            crossed: [0; 3],
+            crossings: diff.map(i128::unsigned_abs),
+        }
+    }
+
+    // Parametric position along the segment where the next lane boundary of an axis is crossed.
+    fn next_crossing(&self, axis: usize) -> f64 {
+        let i = self.crossed[axis] as i128;
+        let boundary = match self.step[axis] > T::ZERO {
+            true => self.start[axis] + i,
+            false => self.start[axis] - 1 - i,
+        };
+        (boundary as f64 - self.origin[axis]) / self.delta[axis]
+    }
+}
+
+impl<T: SignedInt> Iterator for TriangleLine<T> {
+    type Item = Triangle<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.current);
+        }
+
+        // Crossing a lane boundary moves into the neighbor sharing that edge.
+        let axis = (0..3)
+            .filter(|&axis| self.crossed[axis] < self.crossings[axis])
+            .min_by(|&lhs, &rhs| self.next_crossing(lhs).total_cmp(&self.next_crossing(rhs)))?;
+
+        self.crossed[axis] += 1;
+        match axis {
+            0 => self.current.x += self.step[0],
+            1 => self.current.y += self.step[1],
+            _ => self.current.z += self.step[2],
+        }
+
+        Some(self.current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (0..3)
+            .map(|axis| {
+                usize::try_from(self.crossings[axis] - self.crossed[axis]).unwrap_or(usize::MAX)
+            })
+            .fold(!self.started as usize, usize::saturating_add);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: SignedInt> ExactSizeIterator for TriangleLine<T> {}
+impl<T: SignedInt> FusedIterator for TriangleLine<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_size_hint() {
+        for center in [Triangle::new(0, 1, 0), Triangle::new(0, 1, 1)] {
+            for dist in 0..6 {
+                let mut iter = center.range_iter(dist);
+                let mut len = iter.len();
+
+                while let Some(coord) = iter.next() {
+                    assert!(coord.distance_t(center) <= dist);
+                    len -= 1;
+                    assert_eq!(iter.size_hint(), (len, Some(len)));
+                }
+                assert_eq!(len, 0);
+            }
+        }
+
+        assert_eq!(Triangle::new(0, 1, 0).range_iter(-1).next(), None);
+    }
+
+    #[test]
+    fn line_size_hint() {
+        let a = Triangle::new(-1, 0, 2);
+        let b = Triangle::new(2, 1, -1);
+        let mut iter = a.line_iter(b);
+        assert_eq!(iter.len(), a.distance(b) as usize + 1);
+        iter.next();
+        assert_eq!(iter.len(), a.distance(b) as usize);
+        assert_eq!(iter.last(), Some(b));
+    }
+
+    #[test]
+    fn line_is_path() {
+        // Every pair of faces within the range must produce a contiguous path of exact length
+        // which is the reverse of the path produced the other way.
+        let center = Triangle::new(0, 1, 0);
+        for a in center.range_iter(4) {
+            for b in center.range_iter(4) {
+                let mut prev = None;
+                let mut count = 0;
+                for (coord, rev) in a.line_iter(b).zip(line_rev(b, a)) {
+                    assert_eq!(coord, rev);
+                    if let Some(prev) = prev {
+                        assert_eq!(coord.distance(prev), 1);
+                    }
+                    prev = Some(coord);
+                    count += 1;
+                }
+                assert_eq!(count, a.distance(b) + 1);
+                assert_eq!(prev, Some(b));
+            }
+        }
+    }
+
+    // b -> a walked backwards without allocating.
+    fn line_rev(a: Triangle, b: Triangle) -> impl Iterator<Item = Triangle> {
+        let len = a.line_iter(b).len();
+        (0..len).map(move |i| a.line_iter(b).nth(len - 1 - i).unwrap())
+    }
+
+    #[test]
+    fn long_line() {
+        let a = Triangle::new(0, 1, 0);
+        let b = Triangle::new(12, 2, -12);
+        assert_eq!(a.line_iter(b).count(), a.distance(b) as usize + 1);
+        assert_eq!(a.line_iter(b).last(), Some(b));
+    }
+
+    #[test]
+    fn expected_coords() {
+        let coord = Triangle::new(1, 0, 0);
+        assert!(coord.range_iter(2).eq([
+            Triangle::new(0, 0, 1),
+            Triangle::new(0, 1, 0),
+            Triangle::new(1, -1, 1),
+            Triangle::new(1, 0, 0),
+            Triangle::new(1, 0, 1),
+            Triangle::new(1, 1, -1),
+            Triangle::new(1, 1, 0),
+            Triangle::new(2, -1, 0),
+            Triangle::new(2, 0, -1),
+            Triangle::new(2, 0, 0),
+        ]));
+        assert!(coord.line_iter(Triangle::new(-1, 1, 2)).eq([
+            Triangle::new(1, 0, 0),
+            Triangle::new(1, 0, 1),
+            Triangle::new(0, 0, 1),
+            Triangle::new(0, 1, 1),
+            Triangle::new(-1, 1, 1),
+            Triangle::new(-1, 1, 2),
+        ]));
+    }
+}
@@ -0,0 +1,226 @@
+//! Wrap-around topologies for triangle based grids.
+
+use crate::core::topology::Topology;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::lib::*;
+
+use super::Triangle;
+
+impl Triangle {
+    // Move the coordinate by a number of map widths and heights.
+    fn translate_periods(self, topology: Topology, (cols, rows): (i32, i32)) -> Self {
+        let (width, height) = topology.periods();
+        let width = width.unwrap_or_default() * cols;
+        let height = height.unwrap_or_default() * rows;
+
+        // Both translations keep the sum of the coordinate, and with it the orientation.
+        self + Triangle::new(width - height / 2, height, -width - height / 2)
+    }
+
+    /// Canonicalise the coordinate to its position within the map.
+    ///
+    /// Wrapped coordinates have `y` within `0..height` rows and `x - z` within `0..2 * width`,
+    /// two faces per column. On an unbounded axis the coordinate is left as is.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let topology = Topology::cylinder(3).unwrap();
+    /// assert_eq!(Triangle::new(3, 1, -3).wrap(topology), Triangle::new(0, 1, 0));
+    /// ```
+    pub fn wrap(self, topology: Topology) -> Self {
+        let (width, height) = topology.periods();
+        let rows = height.map_or(0, |height| self.y.div_euclid(height));
+        let coord = self.translate_periods(topology, (0, -rows));
+
+        let cols = width.map_or(0, |width| (coord.x - coord.z).div_euclid(2 * width));
+        coord.translate_periods(topology, (-cols, 0))
+    }
+
+    // The copy of b, out of the copies produced by wrapping, nearest to the wrapped self.
+    fn nearest_image(self, b: Self, topology: Topology) -> Self {
+        let a = self.wrap(topology);
+        let b = b.wrap(topology);
+
+        topology
+            .images()
+            .map(|periods| b.translate_periods(topology, periods))
+            .min_by_key(|image| a.distance(*image))
+            .unwrap_or(b)
+    }
+
+    /// Get the wrapped neighbors of this coordinate.
+    ///
+    /// Neighbors across a seam are wrapped to the other side of the map.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let neighbors = Triangle::new(0, 1, 0).wrapped_neighbors(Topology::cylinder(3).unwrap());
+    /// assert!(neighbors.contains(&Triangle::new(3, 1, -2)));
+    /// ```
+    pub fn wrapped_neighbors(self, topology: Topology) -> [Self; 3] {
+        self.wrap(topology)
+            .neighbors()
+            .map(|coord| coord.wrap(topology))
+    }
+
+    /// Compute the distance between two coordinates, taking the shortest route across seams.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let topology = Topology::cylinder(3).unwrap();
+    /// assert_eq!(Triangle::new(0, 1, 0).wrapped_distance(Triangle::new(2, 1, -2), topology), 2);
+    /// ```
+    pub fn wrapped_distance(self, b: Self, topology: Topology) -> u32 {
+        self.wrap(topology)
+            .distance(self.nearest_image(b, topology))
+    }
+
+    /// Produces the wrapped coordinates forming the shortest line between two faces.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// let line = Triangle::new(0, 1, 0).wrapped_line(Triangle::new(2, 1, -2), Topology::cylinder(3).unwrap());
+    /// assert_eq!(line, vec![Triangle::new(0, 1, 0), Triangle::new(3, 1, -2), Triangle::new(2, 1, -2)]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn wrapped_line(self, b: Self, topology: Topology) -> Vec<Self> {
+        self.wrap(topology)
+            .line_iter(self.nearest_image(b, topology))
+            .map(|coord| coord.wrap(topology))
+            .collect()
+    }
+
+    /// Produce the wrapped coordinates within a set distance from this coordinate
+    ///
+    /// Each coordinate appears once, even if the range overlaps itself across a seam.
+    ///
+    /// # Example
+    /// ```
+    /// use gridava::core::topology::Topology;
+    /// use gridava::triangle::coordinate::Triangle;
+    ///
+    /// // A map a single column wide only has 2 faces per row, both in-row neighbors are the same.
+    /// let coords = Triangle::new(0, 1, 0).wrapped_range(1, Topology::cylinder(1).unwrap());
+    /// assert_eq!(coords.len(), 3);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn wrapped_range(self, dist: i32, topology: Topology) -> Vec<Self> {
+        let mut seen = BTreeSet::new();
+
+        self.wrap(topology)
+            .range_iter(dist)
+            .map(|coord| coord.wrap(topology))
+            .filter(|coord| seen.insert((coord.x, coord.y, coord.z)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cylinder() -> Topology {
+        Topology::cylinder(3).unwrap()
+    }
+
+    fn torus() -> Topology {
+        Topology::torus(3, 4).unwrap()
+    }
+
+    #[test]
+    fn wrap() {
+        assert_eq!(
+            Triangle::new(5, -3, 0).wrap(Topology::plane()),
+            Triangle::new(5, -3, 0)
+        );
+
+        assert_eq!(
+            Triangle::new(3, 1, -3).wrap(cylinder()),
+            Triangle::new(0, 1, 0)
+        );
+        assert_eq!(
+            Triangle::new(0, 1, 1).wrap(cylinder()),
+            Triangle::new(3, 1, -2)
+        );
+        assert_eq!(
+            Triangle::new(0, 5, -4).wrap(torus()),
+            Triangle::new(2, 1, -2)
+        );
+
+        for coord in Triangle::new(0, 1, 0).range_iter(8) {
+            let wrapped = coord.wrap(torus());
+            assert_eq!(wrapped.wrap(torus()), wrapped);
+            assert_eq!(wrapped.orientation(), coord.orientation());
+            assert!((0..4).contains(&wrapped.y));
+            assert!((0..6).contains(&(wrapped.x - wrapped.z)));
+        }
+    }
+
+    #[test]
+    fn wrapped_neighbors() {
+        assert_eq!(
+            Triangle::new(0, 1, 0).wrapped_neighbors(Topology::plane()),
+            Triangle::new(0, 1, 0).neighbors()
+        );
+
+        for coord in Triangle::new(0, 1, 0).range_iter(4) {
+            for neighbor in coord.wrapped_neighbors(torus()) {
+                assert_eq!(neighbor.wrapped_distance(coord, torus()), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_distance() {
+        let a = Triangle::new(0, 1, 0);
+        let b = Triangle::new(2, 1, -2);
+        assert_eq!(a.wrapped_distance(b, Topology::plane()), 4);
+        assert_eq!(a.wrapped_distance(b, cylinder()), 2);
+
+        for a in Triangle::new(0, 1, 0).range_iter(3) {
+            for b in Triangle::new(1, 1, 0).range_iter(3) {
+                let dist = a.wrapped_distance(b, torus());
+                assert_eq!(dist, b.wrapped_distance(a, torus()));
+                assert!(dist <= a.distance(b));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn wrapped_line() {
+        let a = Triangle::new(0, 1, 0);
+        let b = Triangle::new(2, 1, -2);
+        assert_eq!(a.wrapped_line(b, Topology::plane()), a.line(b));
+
+        let line = a.wrapped_line(Triangle::new(1, 3, -2), torus());
+        assert_eq!(
+            line.len() as u32,
+            a.wrapped_distance(Triangle::new(1, 3, -2), torus()) + 1
+        );
+        assert!(line.iter().all(|coord| coord.wrap(torus()) == *coord));
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[test]
+    fn wrapped_range() {
+        let a = Triangle::new(0, 1, 0);
+        assert_eq!(a.wrapped_range(2, Topology::plane()), a.range(2));
+        assert_eq!(a.wrapped_range(1, cylinder()).len(), 4);
+
+        // The whole map is within range, 4 rows of 6 faces.
+        assert_eq!(a.wrapped_range(10, torus()).len(), 24);
+    }
+}